@@ -1,3 +1,4 @@
+use crate::math::Angle;
 use serde::{Deserialize, Serialize};
 
 /// Simplified detection record emitted by the processing pipeline.
@@ -29,4 +30,9 @@ impl DetectionRecord {
             elevation_deg,
         }
     }
+
+    /// `bearing_deg` as a normalized, compass-labeled `Angle`.
+    pub fn bearing(&self) -> Angle {
+        Angle::from_degrees(self.bearing_deg)
+    }
 }
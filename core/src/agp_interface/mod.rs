@@ -0,0 +1,7 @@
+pub mod detection;
+pub mod pri;
+pub mod sink;
+
+pub use detection::DetectionRecord;
+pub use pri::{PriAncillary, PriPayload, PriType, ScenarioMetadata};
+pub use sink::{DetectionHub, DetectionSink, MockSink, SinkError, SinkReadiness};
@@ -0,0 +1,240 @@
+use crate::agp_interface::DetectionRecord;
+use crate::telemetry::log::LogManager;
+use std::collections::VecDeque;
+
+/// Whether a `DetectionSink` can currently accept another batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SinkReadiness {
+    Ready,
+    NotReady,
+}
+
+/// A sink's refusal of a batch, carrying whatever error code the transport
+/// underneath it reported.
+#[derive(thiserror::Error, Debug, Clone)]
+#[error("sink rejected batch with code {code}: {message}")]
+pub struct SinkError {
+    pub code: i32,
+    pub message: String,
+}
+
+/// A live consumer of completed-CPI detection batches (GUI bridge, file
+/// logger, future network export). Registered with a `DetectionHub`, which
+/// polls `poll_ready` before every `start_send` so a slow or failing sink
+/// can't block the processing thread.
+pub trait DetectionSink: Send {
+    /// Reports whether this sink can currently accept a batch.
+    fn poll_ready(&mut self) -> SinkReadiness;
+    /// Hands a completed CPI's detection records to the sink. Only called
+    /// by `DetectionHub` after `poll_ready` returns `Ready`.
+    fn start_send(&mut self, records: &[DetectionRecord]) -> Result<(), SinkError>;
+    /// Releases any resources the sink holds; called once, when the hub
+    /// shuts down.
+    fn close(&mut self);
+}
+
+/// Test double that accepts every batch; built with `fail_once`, it fails
+/// exactly once with a fixed error code first — used to exercise `DetectionHub`'s
+/// retry/drop backpressure handling without a real transport.
+#[derive(Default)]
+pub struct MockSink {
+    fail_once_with: Option<i32>,
+    pub received: Vec<Vec<DetectionRecord>>,
+    pub closed: bool,
+}
+
+impl MockSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The sink's next `start_send` call fails with `code`; every call
+    /// after that succeeds.
+    pub fn fail_once(mut self, code: i32) -> Self {
+        self.fail_once_with = Some(code);
+        self
+    }
+}
+
+impl DetectionSink for MockSink {
+    fn poll_ready(&mut self) -> SinkReadiness {
+        SinkReadiness::Ready
+    }
+
+    fn start_send(&mut self, records: &[DetectionRecord]) -> Result<(), SinkError> {
+        if let Some(code) = self.fail_once_with.take() {
+            return Err(SinkError {
+                code,
+                message: "mock sink induced failure".into(),
+            });
+        }
+        self.received.push(records.to_vec());
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        self.closed = true;
+    }
+}
+
+struct Subscriber {
+    sink: Box<dyn DetectionSink>,
+    backlog: VecDeque<Vec<DetectionRecord>>,
+}
+
+/// Broadcast hub fanning completed-CPI detection batches out to every
+/// registered `DetectionSink` (GUI bridge, file logger, future network
+/// export) as `Runner::execute` finishes each burst.
+///
+/// Each subscriber gets its own bounded backlog: a `NotReady` or failing
+/// sink doesn't block the others, and doesn't block the processing thread.
+/// A batch a sink can't yet accept is queued (and retried on the next
+/// `publish` call) until the subscriber's backlog reaches `buffer_depth`,
+/// at which point the oldest queued batch is dropped and counted rather
+/// than growing without limit.
+pub struct DetectionHub {
+    subscribers: Vec<Subscriber>,
+    buffer_depth: usize,
+    logger: LogManager,
+    dropped_batches: usize,
+}
+
+impl DetectionHub {
+    pub fn new(buffer_depth: usize) -> Self {
+        Self {
+            subscribers: Vec::new(),
+            buffer_depth: buffer_depth.max(1),
+            logger: LogManager::new(),
+            dropped_batches: 0,
+        }
+    }
+
+    pub fn subscribe(&mut self, sink: Box<dyn DetectionSink>) {
+        self.subscribers.push(Subscriber {
+            sink,
+            backlog: VecDeque::new(),
+        });
+    }
+
+    /// Fans `records` out to every subscriber, buffering or dropping per
+    /// the backpressure policy described on the type. Returns whether every
+    /// subscriber accepted the batch immediately — `false` if any
+    /// subscriber is still retrying it (queued) or had to drop it — which
+    /// is the signal a `CheckpointManager` waits for before advancing its
+    /// watermark past this CPI: a lagging or failing sink must not let the
+    /// watermark run ahead of what's actually been delivered.
+    pub fn publish(&mut self, records: &[DetectionRecord]) -> bool {
+        let mut all_accepted = true;
+        for subscriber in &mut self.subscribers {
+            subscriber.backlog.push_back(records.to_vec());
+            if subscriber.backlog.len() > self.buffer_depth {
+                subscriber.backlog.pop_front();
+                self.dropped_batches += 1;
+                all_accepted = false;
+                self.logger
+                    .record("DetectionHub dropped a batch, subscriber backlog at capacity");
+            }
+
+            while let Some(batch) = subscriber.backlog.front() {
+                match subscriber.sink.poll_ready() {
+                    SinkReadiness::Ready => {
+                        let batch = batch.clone();
+                        match subscriber.sink.start_send(&batch) {
+                            Ok(()) => {
+                                subscriber.backlog.pop_front();
+                            }
+                            Err(err) => {
+                                self.logger
+                                    .record(&format!("DetectionSink rejected a batch: {err}"));
+                                break;
+                            }
+                        }
+                    }
+                    SinkReadiness::NotReady => break,
+                }
+            }
+
+            if !subscriber.backlog.is_empty() {
+                all_accepted = false;
+            }
+        }
+        all_accepted
+    }
+
+    /// Total batches dropped across every subscriber due to a full backlog.
+    pub fn dropped_batches(&self) -> usize {
+        self.dropped_batches
+    }
+
+    /// Closes every subscriber, in subscription order.
+    pub fn close(&mut self) {
+        for subscriber in &mut self.subscribers {
+            subscriber.sink.close();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<DetectionRecord> {
+        vec![DetectionRecord::new(0.0, 1.0, 0.0, 3.0, 0.0, 0.0)]
+    }
+
+    #[test]
+    fn hub_delivers_batches_to_a_ready_sink() {
+        let mut hub = DetectionHub::new(4);
+        let sink = MockSink::new();
+        let received = {
+            hub.subscribe(Box::new(sink));
+            assert!(hub.publish(&records()));
+            hub.dropped_batches()
+        };
+        assert_eq!(received, 0);
+    }
+
+    #[test]
+    fn hub_publish_reports_whether_every_subscriber_accepted() {
+        let mut hub = DetectionHub::new(4);
+        hub.subscribe(Box::new(MockSink::new()));
+        assert!(hub.publish(&records()));
+
+        let mut hub = DetectionHub::new(4);
+        hub.subscribe(Box::new(MockSink::new().fail_once(7)));
+        assert!(!hub.publish(&records()));
+    }
+
+    #[test]
+    fn hub_retries_a_batch_after_a_failed_send() {
+        let mut hub = DetectionHub::new(4);
+        hub.subscribe(Box::new(MockSink::new().fail_once(7)));
+
+        hub.publish(&records());
+        assert_eq!(hub.dropped_batches(), 0);
+
+        // The failed batch is still queued; the next publish call retries
+        // it (now succeeding) ahead of the newly published one.
+        assert!(hub.publish(&records()));
+        assert_eq!(hub.dropped_batches(), 0);
+    }
+
+    #[test]
+    fn hub_drops_oldest_batch_once_backlog_is_full() {
+        let mut hub = DetectionHub::new(1);
+        hub.subscribe(Box::new(MockSink::new().fail_once(7)));
+
+        hub.publish(&records());
+        hub.publish(&records());
+        hub.publish(&records());
+
+        assert!(hub.dropped_batches() >= 1);
+    }
+
+    #[test]
+    fn hub_closes_every_subscriber() {
+        let mut hub = DetectionHub::new(4);
+        hub.subscribe(Box::new(MockSink::new()));
+        hub.close();
+    }
+}
@@ -0,0 +1,212 @@
+use super::wgpu_util::{storage_entry, uniform_entry};
+use anyhow::{anyhow, Context};
+use ndarray::Array2;
+use num_complex::Complex32;
+use wgpu::util::DeviceExt;
+
+const SHADER_SOURCE: &str = include_str!("doppler_transform.wgsl");
+
+#[repr(C)]
+#[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct Dims {
+    pulses: u32,
+    range_bins: u32,
+    doppler_bins: u32,
+    _padding: u32,
+}
+
+/// Cached GPU device, queue, and compute pipeline for the Doppler transform
+/// shader. Built once (in `DopplerStage::initialize`, via `new`) and reused
+/// across every `execute` call — re-requesting a device and recompiling the
+/// shader per burst would dwarf the transform itself.
+pub struct GpuDopplerBackend {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    doppler_bins: usize,
+}
+
+impl GpuDopplerBackend {
+    /// Requests an adapter/device and compiles the Doppler-transform compute
+    /// pipeline. Returns an error rather than panicking when no adapter is
+    /// available, so `DopplerStage::initialize` can fall back to the CPU
+    /// path.
+    pub fn new(doppler_bins: usize) -> anyhow::Result<Self> {
+        pollster::block_on(Self::new_async(doppler_bins))
+    }
+
+    async fn new_async(doppler_bins: usize) -> anyhow::Result<Self> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                ..Default::default()
+            })
+            .await
+            .ok_or_else(|| anyhow!("no wgpu adapter available for the Doppler GPU backend"))?;
+
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .await
+            .context("requesting a wgpu device for the Doppler GPU backend")?;
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("doppler_transform"),
+            source: wgpu::ShaderSource::Wgsl(SHADER_SOURCE.into()),
+        });
+
+        let bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("doppler_transform_layout"),
+                entries: &[
+                    uniform_entry(0),
+                    storage_entry(1, true),
+                    storage_entry(2, true),
+                    storage_entry(3, false),
+                ],
+            });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("doppler_transform_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("doppler_transform_pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: "doppler_transform",
+        });
+
+        Ok(Self {
+            device,
+            queue,
+            pipeline,
+            bind_group_layout,
+            doppler_bins,
+        })
+    }
+
+    /// Runs the Doppler transform for `matrix` (a `(pulses, range_bins)`
+    /// complex matrix) on the GPU, applying `taper` per pulse, and returns
+    /// the `(doppler_bins, range_bins)` spectrum.
+    pub fn transform(
+        &self,
+        matrix: &Array2<Complex32>,
+        taper: &[f32],
+    ) -> anyhow::Result<Array2<Complex32>> {
+        let (pulses, range_bins) = matrix.dim();
+        let doppler_bins = self.doppler_bins;
+
+        let dims = Dims {
+            pulses: pulses as u32,
+            range_bins: range_bins as u32,
+            doppler_bins: doppler_bins as u32,
+            _padding: 0,
+        };
+
+        let input_data: Vec<[f32; 2]> = matrix.iter().map(|value| [value.re, value.im]).collect();
+
+        let dims_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("doppler_dims"),
+                contents: bytemuck::bytes_of(&dims),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let input_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("doppler_input"),
+                contents: bytemuck::cast_slice(&input_data),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+        let taper_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("doppler_taper"),
+                contents: bytemuck::cast_slice(taper),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+
+        let output_len = (doppler_bins * range_bins * std::mem::size_of::<[f32; 2]>()) as u64;
+        let output_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("doppler_output"),
+            size: output_len,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let staging_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("doppler_staging"),
+            size: output_len,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("doppler_transform_bind_group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: dims_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: input_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: taper_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: output_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("doppler_transform_encoder"),
+            });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("doppler_transform_pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            let workgroups_x = (doppler_bins as u32).div_ceil(8);
+            let workgroups_y = (range_bins as u32).div_ceil(8);
+            pass.dispatch_workgroups(workgroups_x, workgroups_y, 1);
+        }
+        encoder.copy_buffer_to_buffer(&output_buffer, 0, &staging_buffer, 0, output_len);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = staging_buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .context("waiting for the Doppler GPU readback")?
+            .context("mapping the Doppler GPU output buffer")?;
+
+        let data = slice.get_mapped_range();
+        let raw: &[[f32; 2]] = bytemuck::cast_slice(&data);
+        let spectrum = Array2::from_shape_fn((doppler_bins, range_bins), |(doppler_bin, range_bin)| {
+            let cell = raw[doppler_bin * range_bins + range_bin];
+            Complex32::new(cell[0], cell[1])
+        });
+        drop(data);
+        staging_buffer.unmap();
+
+        Ok(spectrum)
+    }
+}
@@ -0,0 +1,221 @@
+use super::wgpu_util::{storage_entry, uniform_entry};
+use crate::math::fft::FftBackend;
+use anyhow::{anyhow, bail, Context};
+use num_complex::Complex32;
+use wgpu::util::DeviceExt;
+
+const SHADER_SOURCE: &str = include_str!("radix2_fft.wgsl");
+
+#[repr(C)]
+#[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct StageParams {
+    span: u32,
+    size: u32,
+}
+
+/// GPU radix-2 Cooley-Tukey FFT backend: the bit-reversal permutation and
+/// twiddle factors are precomputed host-side once, at construction, and
+/// `forward` re-dispatches the cached pipeline — one compute pass per
+/// butterfly stage — on every call. Only services power-of-two sizes;
+/// `new` rejects anything else so `FftHelper::with_backend_preference` can
+/// fall back to the CPU backend instead.
+pub struct GpuRadix2FftBackend {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    twiddle_buffer: wgpu::Buffer,
+    size: usize,
+    log2_size: u32,
+}
+
+impl GpuRadix2FftBackend {
+    pub fn new(size: usize) -> anyhow::Result<Self> {
+        if size == 0 || !size.is_power_of_two() {
+            bail!("GPU radix-2 FFT backend requires a power-of-two size, got {size}");
+        }
+        pollster::block_on(Self::new_async(size))
+    }
+
+    async fn new_async(size: usize) -> anyhow::Result<Self> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                ..Default::default()
+            })
+            .await
+            .ok_or_else(|| anyhow!("no wgpu adapter available for the GPU FFT backend"))?;
+
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .await
+            .context("requesting a wgpu device for the GPU FFT backend")?;
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("radix2_fft"),
+            source: wgpu::ShaderSource::Wgsl(SHADER_SOURCE.into()),
+        });
+
+        let bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("radix2_fft_layout"),
+                entries: &[uniform_entry(0), storage_entry(1, true), storage_entry(2, false)],
+            });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("radix2_fft_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("radix2_fft_pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: "fft_stage",
+        });
+
+        let twiddles = precompute_twiddles(size);
+        let twiddle_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("radix2_fft_twiddles"),
+            contents: bytemuck::cast_slice(&twiddles),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+
+        Ok(Self {
+            device,
+            queue,
+            pipeline,
+            bind_group_layout,
+            twiddle_buffer,
+            size,
+            log2_size: size.trailing_zeros(),
+        })
+    }
+
+    fn forward_inner(&self, input: &[Complex32]) -> Vec<Complex32> {
+        let size = self.size;
+        let reordered = bit_reverse_permute(input, size);
+        let data_values: Vec<[f32; 2]> = reordered.iter().map(|c| [c.re, c.im]).collect();
+
+        let data_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("radix2_fft_data"),
+                contents: bytemuck::cast_slice(&data_values),
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            });
+
+        let params_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("radix2_fft_params"),
+            size: std::mem::size_of::<StageParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("radix2_fft_bind_group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: self.twiddle_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: data_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let workgroup_count = ((size as u32) / 2).max(1).div_ceil(64);
+        for stage in 0..self.log2_size {
+            let params = StageParams {
+                span: 1u32 << stage,
+                size: size as u32,
+            };
+            self.queue
+                .write_buffer(&params_buffer, 0, bytemuck::bytes_of(&params));
+
+            let mut encoder = self
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("radix2_fft_stage_encoder"),
+                });
+            {
+                let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: Some("radix2_fft_stage_pass"),
+                    timestamp_writes: None,
+                });
+                pass.set_pipeline(&self.pipeline);
+                pass.set_bind_group(0, &bind_group, &[]);
+                pass.dispatch_workgroups(workgroup_count, 1, 1);
+            }
+            self.queue.submit(Some(encoder.finish()));
+        }
+
+        let output_len = (size * std::mem::size_of::<[f32; 2]>()) as u64;
+        let staging_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("radix2_fft_staging"),
+            size: output_len,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("radix2_fft_readback_encoder"),
+            });
+        encoder.copy_buffer_to_buffer(&data_buffer, 0, &staging_buffer, 0, output_len);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = staging_buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .expect("GPU FFT readback channel closed")
+            .expect("mapping the GPU FFT output buffer");
+
+        let mapped = slice.get_mapped_range();
+        let raw: &[[f32; 2]] = bytemuck::cast_slice(&mapped);
+        let result = raw.iter().map(|cell| Complex32::new(cell[0], cell[1])).collect();
+        drop(mapped);
+        staging_buffer.unmap();
+        result
+    }
+}
+
+impl FftBackend for GpuRadix2FftBackend {
+    fn forward(&mut self, input: &[Complex32]) -> Vec<Complex32> {
+        self.forward_inner(input)
+    }
+}
+
+fn bit_reverse_permute(input: &[Complex32], size: usize) -> Vec<Complex32> {
+    let bits = size.trailing_zeros();
+    let mut out = vec![Complex32::new(0.0, 0.0); size];
+    for (i, slot) in out.iter_mut().enumerate() {
+        let reversed = (i as u32).reverse_bits() >> (32 - bits.max(1));
+        *slot = input.get(reversed as usize).copied().unwrap_or_default();
+    }
+    out
+}
+
+fn precompute_twiddles(size: usize) -> Vec<[f32; 2]> {
+    let half = (size / 2).max(1);
+    (0..half)
+        .map(|k| {
+            let angle = -2.0 * std::f32::consts::PI * k as f32 / size as f32;
+            [angle.cos(), angle.sin()]
+        })
+        .collect()
+}
@@ -0,0 +1,179 @@
+use super::wgpu_util::{storage_entry, uniform_entry};
+use anyhow::{anyhow, Context};
+use ndarray::Array2;
+use num_complex::Complex32;
+use wgpu::util::DeviceExt;
+
+const SHADER_SOURCE: &str = include_str!("power_profile.wgsl");
+
+#[repr(C)]
+#[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct Dims {
+    pulses: u32,
+    range_bins: u32,
+}
+
+/// Cached GPU device, queue, and compute pipeline for `RangeStage`'s
+/// per-range-bin power profile (mean squared magnitude across pulses).
+/// Built once, in `RangeStage::initialize`, via `new`, and reused across
+/// every `execute` call.
+pub struct GpuPowerProfileBackend {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+}
+
+impl GpuPowerProfileBackend {
+    /// Requests an adapter/device and compiles the power-profile compute
+    /// pipeline. Returns an error rather than panicking when no adapter is
+    /// available, so `RangeStage::initialize` can fall back to the CPU
+    /// path.
+    pub fn new() -> anyhow::Result<Self> {
+        pollster::block_on(Self::new_async())
+    }
+
+    async fn new_async() -> anyhow::Result<Self> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                ..Default::default()
+            })
+            .await
+            .ok_or_else(|| anyhow!("no wgpu adapter available for the power-profile GPU backend"))?;
+
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .await
+            .context("requesting a wgpu device for the power-profile GPU backend")?;
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("power_profile"),
+            source: wgpu::ShaderSource::Wgsl(SHADER_SOURCE.into()),
+        });
+
+        let bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("power_profile_layout"),
+                entries: &[uniform_entry(0), storage_entry(1, true), storage_entry(2, false)],
+            });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("power_profile_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("power_profile_pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: "power_profile_main",
+        });
+
+        Ok(Self {
+            device,
+            queue,
+            pipeline,
+            bind_group_layout,
+        })
+    }
+
+    /// Computes one mean-squared-magnitude value per range bin from a
+    /// `(pulses, range_bins)` complex matrix, uploading the whole sample
+    /// block in a single `create_buffer_init` call rather than
+    /// allocating-then-writing.
+    pub fn power_profile(&self, matrix: &Array2<Complex32>) -> anyhow::Result<Vec<f32>> {
+        let (pulses, range_bins) = matrix.dim();
+        let dims = Dims {
+            pulses: pulses as u32,
+            range_bins: range_bins as u32,
+        };
+
+        let samples: Vec<[f32; 2]> = matrix.iter().map(|value| [value.re, value.im]).collect();
+
+        let dims_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("power_profile_dims"),
+                contents: bytemuck::bytes_of(&dims),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let input_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("power_profile_input"),
+                contents: bytemuck::cast_slice(&samples),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+
+        let output_len = (range_bins * std::mem::size_of::<f32>()) as u64;
+        let output_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("power_profile_output"),
+            size: output_len,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let staging_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("power_profile_staging"),
+            size: output_len,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("power_profile_bind_group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: dims_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: input_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: output_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("power_profile_encoder"),
+            });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("power_profile_pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups((range_bins as u32).div_ceil(64), 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&output_buffer, 0, &staging_buffer, 0, output_len);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = staging_buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .context("waiting for the power-profile GPU readback")?
+            .context("mapping the power-profile GPU output buffer")?;
+
+        let mapped = slice.get_mapped_range();
+        let power_profile: Vec<f32> = bytemuck::cast_slice(&mapped).to_vec();
+        drop(mapped);
+        staging_buffer.unmap();
+
+        Ok(power_profile)
+    }
+}
@@ -0,0 +1,20 @@
+//! Optional GPU compute backends, gated behind the `gpu` feature so a
+//! pure-CPU build never pulls in `wgpu`. Stages that offer a GPU path treat
+//! it as a per-stage cache-and-fallback concern local to their own
+//! `initialize`/`execute`, not a pipeline-wide requirement.
+
+#[cfg(feature = "gpu")]
+pub mod gpu_fft;
+#[cfg(feature = "gpu")]
+pub mod gpu_fft_radix2;
+#[cfg(feature = "gpu")]
+pub mod gpu_power_profile;
+#[cfg(feature = "gpu")]
+mod wgpu_util;
+
+#[cfg(feature = "gpu")]
+pub use gpu_fft::GpuDopplerBackend;
+#[cfg(feature = "gpu")]
+pub use gpu_fft_radix2::GpuRadix2FftBackend;
+#[cfg(feature = "gpu")]
+pub use gpu_power_profile::GpuPowerProfileBackend;
@@ -2,8 +2,26 @@
 //!
 //! The modules mirror the legacy AESADIRP/AIRRADAR pipeline while providing
 //! safe abstractions, scoped buffers, and well-defined processing stages.
+//!
+//! # `no_std`
+//!
+//! With the `std` feature off (and `alloc` on) this crate builds for
+//! embedded radar front-ends: `math` and the `telemetry::log` module only
+//! need heap allocation, not an OS, and most of `processing` follows —
+//! `buffer_pool`, `cfar`, `clutter`, and `range` build the same way. The
+//! exception is `processing::doppler`, which needs a working FFT backend
+//! and so additionally requires `std` or `gpu` — see `DopplerStage`'s docs.
+//! `telemetry::mavlink` and `telemetry::mqtt` talk to hosted-only transports
+//! (sockets, serial ports, MQTT brokers) and stay behind `std`. The GPU
+//! `compute` backends are likewise hosted-only and already gated behind the
+//! separate `gpu` feature.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod agp_interface;
+#[cfg(feature = "gpu")]
+pub mod compute;
 pub mod math;
 pub mod prelude;
 pub mod processing;
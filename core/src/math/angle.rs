@@ -0,0 +1,76 @@
+use libm::roundf;
+
+/// A bearing in degrees, always normalized to `[0, 360)`, so callers never
+/// have to re-derive "which way is that really pointing" after arithmetic
+/// that strays outside the usual range (e.g. summing a platform heading and
+/// a relative bearing).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Angle(f32);
+
+const COMPASS_POINTS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+
+impl Angle {
+    /// Wraps `degrees` into `[0, 360)`.
+    pub fn from_degrees(degrees: f32) -> Self {
+        let wrapped = degrees % 360.0;
+        Self(if wrapped < 0.0 { wrapped + 360.0 } else { wrapped })
+    }
+
+    pub fn degrees(&self) -> f32 {
+        self.0
+    }
+
+    pub fn to_radians(&self) -> f32 {
+        self.0.to_radians()
+    }
+
+    /// Nearest 8-point compass label ("N", "NE", "E", ...), rounding ties
+    /// toward the following point.
+    pub fn compass_label(&self) -> &'static str {
+        let index = (roundf(self.0 / 45.0) as usize) % COMPASS_POINTS.len();
+        COMPASS_POINTS[index]
+    }
+}
+
+impl core::ops::Add for Angle {
+    type Output = Angle;
+
+    fn add(self, rhs: Angle) -> Angle {
+        Angle::from_degrees(self.0 + rhs.0)
+    }
+}
+
+impl core::ops::Sub for Angle {
+    type Output = Angle;
+
+    fn sub(self, rhs: Angle) -> Angle {
+        Angle::from_degrees(self.0 - rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_degrees_wrap_into_range() {
+        assert_eq!(Angle::from_degrees(-30.0).degrees(), 330.0);
+    }
+
+    #[test]
+    fn degrees_at_or_above_360_wrap_down() {
+        assert_eq!(Angle::from_degrees(450.0).degrees(), 90.0);
+    }
+
+    #[test]
+    fn compass_label_picks_the_nearest_eight_point() {
+        assert_eq!(Angle::from_degrees(0.0).compass_label(), "N");
+        assert_eq!(Angle::from_degrees(80.0).compass_label(), "E");
+        assert_eq!(Angle::from_degrees(181.0).compass_label(), "S");
+    }
+
+    #[test]
+    fn addition_wraps_around_the_compass() {
+        assert_eq!((Angle::from_degrees(350.0) + Angle::from_degrees(20.0)).degrees(), 10.0);
+    }
+}
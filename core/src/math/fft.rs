@@ -1,30 +1,104 @@
+use crate::prelude::ComputeBackend;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 use num_complex::Complex32;
+#[cfg(feature = "std")]
 use rustfft::{num_traits::Zero, Fft, FftPlanner};
 
-/// Helper that wraps the `rustfft` planner for reuse.
+/// Where a forward FFT of a fixed `size` actually executes, so `FftHelper`
+/// can swap in a GPU implementation without its callers knowing. Every
+/// implementation is expected to always service a call of the size it was
+/// built for — size/availability checks belong at construction time (see
+/// `GpuRadix2FftBackend::new`), not in `forward`.
+pub trait FftBackend: Send {
+    fn forward(&mut self, input: &[Complex32]) -> Vec<Complex32>;
+}
+
+/// `rustfft`'s planner assumes an OS allocator and thread-pool friendly
+/// `Arc`, so this backend — and `FftHelper::new`, which always builds one —
+/// stays behind `std`. An embedded front-end on the `gpu` feature skips it
+/// entirely via `with_backend_preference`; one with neither `std` nor `gpu`
+/// has no working `FftBackend` yet.
+#[cfg(feature = "std")]
+struct CpuFftBackend {
+    fft: Arc<dyn Fft<f32>>,
+    size: usize,
+}
+
+#[cfg(feature = "std")]
+impl CpuFftBackend {
+    fn new(size: usize) -> Self {
+        let mut planner = FftPlanner::new();
+        let fft = planner.plan_fft_forward(size);
+        Self { fft, size }
+    }
+}
+
+#[cfg(feature = "std")]
+impl FftBackend for CpuFftBackend {
+    fn forward(&mut self, input: &[Complex32]) -> Vec<Complex32> {
+        let mut buffer = input.to_vec();
+        buffer.resize(self.size, Complex32::zero());
+        self.fft.process(&mut buffer);
+        buffer
+    }
+}
+
+/// Helper that wraps an `FftBackend` for reuse, preferring a GPU backend
+/// when requested and available, and otherwise running on the CPU via
+/// `rustfft`.
 pub struct FftHelper {
-    fft: std::sync::Arc<dyn Fft<f32>>,
-    scratch: Vec<Complex32>,
+    size: usize,
+    backend: Box<dyn FftBackend>,
 }
 
 impl FftHelper {
+    #[cfg(feature = "std")]
     pub fn new(size: usize) -> Self {
-        let mut planner = FftPlanner::new();
-        let fft = planner.plan_fft_forward(size);
-        let scratch = vec![Complex32::zero(); size];
-        Self { fft, scratch }
+        Self {
+            size,
+            backend: Box::new(CpuFftBackend::new(size)),
+        }
+    }
+
+    /// Like `new`, but tries a GPU radix-2 Cooley-Tukey backend first when the
+    /// `gpu` feature is enabled and `backend` requests it, falling back to
+    /// the CPU backend when no adapter is available or `size` isn't a
+    /// power of two. On a `no_std` build without `gpu`, there is no CPU
+    /// fallback to land on — construct a GPU-backed helper instead.
+    #[cfg(any(feature = "std", feature = "gpu"))]
+    pub fn with_backend_preference(size: usize, backend: ComputeBackend) -> Self {
+        #[cfg(feature = "gpu")]
+        if backend == ComputeBackend::Gpu {
+            if let Ok(gpu_backend) = crate::compute::GpuRadix2FftBackend::new(size) {
+                return Self {
+                    size,
+                    backend: Box::new(gpu_backend),
+                };
+            }
+        }
+        #[cfg(not(feature = "gpu"))]
+        let _ = backend;
+
+        Self::new(size)
     }
 
     pub fn forward(&mut self, input: &[f32]) -> Vec<Complex32> {
-        let mut buffer: Vec<Complex32> = input
+        let buffer: Vec<Complex32> = input
             .iter()
             .map(|&value| Complex32::new(value, 0.0))
             .collect();
-        buffer.resize(self.scratch.len(), Complex32::zero());
+        self.forward_complex(&buffer)
+    }
 
-        self.scratch.copy_from_slice(&buffer);
-        self.fft.process(&mut buffer);
-        buffer
+    /// Same as `forward`, but for input that is already complex — used for
+    /// the slow-time FFT along `DopplerStage`'s pulses axis, where the
+    /// per-range-bin lane has already been Hamming-tapered.
+    pub fn forward_complex(&mut self, input: &[Complex32]) -> Vec<Complex32> {
+        let mut buffer = input.to_vec();
+        buffer.resize(self.size, Complex32::new(0.0, 0.0));
+        self.backend.forward(&buffer)
     }
 }
 
@@ -38,4 +112,11 @@ mod tests {
         let output = helper.forward(&[1.0, 0.0, -1.0, 0.0]);
         assert_eq!(output.len(), 4);
     }
+
+    #[test]
+    fn fft_helper_falls_back_to_cpu_without_gpu_backend() {
+        let mut helper = FftHelper::with_backend_preference(4, ComputeBackend::Gpu);
+        let output = helper.forward(&[1.0, 0.0, -1.0, 0.0]);
+        assert_eq!(output.len(), 4);
+    }
 }
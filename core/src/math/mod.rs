@@ -1,7 +1,9 @@
+pub mod angle;
 pub mod fft;
 pub mod matrix;
 pub mod stats;
 
+pub use angle::Angle;
 pub use fft::FftHelper;
 pub use matrix::MatrixHelper;
 pub use stats::StatsHelper;
@@ -1,3 +1,8 @@
+use alloc::vec::Vec;
+use libm::sqrtf;
+use ndarray::{Array2, Axis};
+use num_complex::Complex32;
+
 pub struct StatsHelper;
 
 impl StatsHelper {
@@ -6,7 +11,20 @@ impl StatsHelper {
             return 0.0;
         }
         let sum_sq: f32 = samples.iter().map(|&v| v * v).sum();
-        (sum_sq / samples.len() as f32).sqrt()
+        sqrtf(sum_sq / samples.len() as f32)
+    }
+
+    /// RMS of `matrix`'s magnitudes, collapsed along `axis` into one value
+    /// per lane — e.g. `Axis(1)` on a `(pulses, range_bins)` matrix yields
+    /// one RMS per range bin across all pulses.
+    pub fn rms_per_axis(matrix: &Array2<Complex32>, axis: Axis) -> Vec<f32> {
+        matrix
+            .axis_iter(axis)
+            .map(|lane| {
+                let magnitudes: Vec<f32> = lane.iter().map(|value| value.norm()).collect();
+                Self::rms(&magnitudes)
+            })
+            .collect()
     }
 }
 
@@ -24,4 +42,14 @@ mod tests {
     fn rms_handles_single_value() {
         assert_eq!(StatsHelper::rms(&[4.0]), 4.0);
     }
+
+    #[test]
+    fn rms_per_axis_collapses_columns() {
+        let matrix = Array2::from_shape_fn((2, 3), |(row, col)| {
+            Complex32::new((row * 3 + col) as f32, 0.0)
+        });
+        let per_range_bin = StatsHelper::rms_per_axis(&matrix, Axis(1));
+        assert_eq!(per_range_bin.len(), 3);
+        assert_eq!(per_range_bin[0], StatsHelper::rms(&[0.0, 3.0]));
+    }
 }
@@ -1,24 +1,101 @@
+use crate::agp_interface::DetectionRecord;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use ndarray::Array2;
+use num_complex::Complex32;
 use serde::{Deserialize, Serialize};
 
+/// Selects which backend `DopplerStage` and `RangeStage` run their
+/// transform/power-profile work on. `Gpu` is a preference, not a
+/// guarantee — stages fall back to `Cpu` whenever the `gpu` feature is off,
+/// no adapter is available, or (for the FFT path) the extent isn't a power
+/// of two.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum ComputeBackend {
+    #[default]
+    Cpu,
+    Gpu,
+}
+
 /// Shared configuration for each processing stage.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StageConfig {
     pub taps: usize,
     pub range_bins: usize,
     pub doppler_bins: usize,
+    /// `CfarStage` guard-cell half-width `G` around the cell under test, in
+    /// both range and Doppler.
+    pub cfar_guard_cells: usize,
+    /// `CfarStage` training-cell half-width `T` surrounding the guard band.
+    pub cfar_training_cells: usize,
+    /// `CfarStage` target probability of false alarm `P_fa`, used to derive
+    /// the threshold scale factor `α = N·(P_fa^(-1/N) − 1)`, where `N` is the
+    /// number of training cells actually inside the (edge-clamped) window.
+    pub cfar_false_alarm_rate: f32,
+    /// Which backend `DopplerStage` should prefer for its slow-time
+    /// transform.
+    pub backend: ComputeBackend,
+}
+
+impl Default for StageConfig {
+    fn default() -> Self {
+        Self {
+            taps: 0,
+            range_bins: 0,
+            doppler_bins: 0,
+            cfar_guard_cells: 2,
+            cfar_training_cells: 8,
+            cfar_false_alarm_rate: 1e-3,
+            backend: ComputeBackend::default(),
+        }
+    }
 }
 
+/// 2-D complex sample matrix carried between stages in place of a flat,
+/// hand-reshaped `Vec<f32>`. `RangeStage` consumes and produces a
+/// `(pulses, range_bins)` matrix; `DopplerStage` consumes that and produces
+/// a `(doppler_bins, range_bins)` range-Doppler matrix.
+pub type ComplexMatrix = Array2<Complex32>;
+
 /// Input payload for a processing stage.
 #[derive(Debug, Clone)]
 pub struct StageInput {
-    pub samples: Vec<f32>,
+    pub matrix: ComplexMatrix,
     pub timestamp: Option<f64>,
 }
 
+impl StageInput {
+    /// Reshapes a flat, row-major `(pulses, range_bins)` block of real
+    /// samples — the layout the simulator's generator already produces —
+    /// into a complex `StageInput`, centralizing the `pulses * range_bins`
+    /// bookkeeping that used to be repeated at each call site.
+    pub fn from_real_samples(
+        samples: &[f32],
+        pulses: usize,
+        range_bins: usize,
+        timestamp: Option<f64>,
+    ) -> StageResult<Self> {
+        let expected = pulses
+            .checked_mul(range_bins)
+            .ok_or_else(|| StageError::InvalidInput("pulses * range_bins overflowed".into()))?;
+        if samples.len() < expected {
+            return Err(StageError::InvalidInput(format!(
+                "expected at least {expected} samples for a {pulses}x{range_bins} matrix, got {}",
+                samples.len()
+            )));
+        }
+        let matrix = Array2::from_shape_fn((pulses, range_bins), |(row, col)| {
+            Complex32::new(samples[row * range_bins + col], 0.0)
+        });
+        Ok(Self { matrix, timestamp })
+    }
+}
+
 /// Output produced by each stage.
 #[derive(Debug, Clone)]
 pub struct StageOutput {
-    pub samples: Vec<f32>,
+    pub matrix: ComplexMatrix,
     pub metadata: StageMetadata,
 }
 
@@ -27,10 +104,14 @@ pub struct StageOutput {
 pub struct StageMetadata {
     pub power_profile: Option<Vec<f32>>,
     pub detection_count: Option<usize>,
+    pub detection_records: Vec<DetectionRecord>,
     pub notes: Vec<String>,
 }
 
-/// Common error type for stage execution.
+/// Common error type for stage execution. Built on `thiserror`'s `no_std`
+/// support (display-only `Error` impl, no backtrace capture), so it stays
+/// available to `BufferPool` and the rest of `processing` under `no_std` +
+/// `alloc`.
 #[derive(thiserror::Error, Debug)]
 pub enum StageError {
     #[error("buffer exhaustion: {0}")]
@@ -43,8 +124,10 @@ pub enum StageError {
 
 pub type StageResult<T> = Result<T, StageError>;
 
-/// Trait describing object-oriented signal-processing stages.
-pub trait ProcessingStage {
+/// Trait describing object-oriented signal-processing stages. `Send` so a
+/// `Runner` can hand independent CPI blocks to different rayon workers in
+/// `execute_parallel`, each owning its own stage instances.
+pub trait ProcessingStage: Send {
     fn initialize(&mut self, config: &StageConfig) -> StageResult<()>;
     fn execute(&mut self, input: StageInput) -> StageResult<StageOutput>;
     fn cleanup(&mut self);
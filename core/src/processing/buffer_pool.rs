@@ -1,7 +1,22 @@
 use crate::prelude::StageError;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
 
-/// Simple scoped buffer pool that prevents unbounded allocations.
+/// Simple scoped buffer pool that prevents unbounded allocations. Only
+/// depends on `alloc`, so it also builds on embedded `no_std` front-ends.
+///
+/// With `std` on, `checkout`/`release` take `&self` and guard the backing
+/// stack with a `Mutex` so `Runner::execute_parallel` can share one pool
+/// across rayon workers without races; a `no_std` build has no worker pool
+/// to share with, so it keeps the original single-threaded `&mut self`
+/// stack instead of paying for a lock nothing contends on.
 pub struct BufferPool {
+    #[cfg(feature = "std")]
+    buffers: Mutex<Vec<Vec<f32>>>,
+    #[cfg(not(feature = "std"))]
     buffers: Vec<Vec<f32>>,
     max_capacity: usize,
 }
@@ -9,12 +24,33 @@ pub struct BufferPool {
 impl BufferPool {
     pub fn with_capacity(max_capacity: usize) -> Self {
         Self {
+            #[cfg(feature = "std")]
+            buffers: Mutex::new(Vec::with_capacity(max_capacity)),
+            #[cfg(not(feature = "std"))]
             buffers: Vec::with_capacity(max_capacity),
             max_capacity,
         }
     }
 
     /// Allocates a buffer from the pool or creates one if there is room.
+    #[cfg(feature = "std")]
+    pub fn checkout(&self, length: usize) -> Result<Vec<f32>, StageError> {
+        let mut buffers = self
+            .buffers
+            .lock()
+            .map_err(|_| StageError::Internal("buffer pool lock poisoned".to_string()))?;
+        if let Some(mut buffer) = buffers.pop() {
+            buffer.resize(length, 0.0);
+            Ok(buffer)
+        } else if buffers.len() < self.max_capacity {
+            Ok(vec![0.0; length])
+        } else {
+            Err(StageError::BufferExhaustion("pool depleted".to_string()))
+        }
+    }
+
+    /// Allocates a buffer from the pool or creates one if there is room.
+    #[cfg(not(feature = "std"))]
     pub fn checkout(&mut self, length: usize) -> Result<Vec<f32>, StageError> {
         if let Some(mut buffer) = self.buffers.pop() {
             buffer.resize(length, 0.0);
@@ -27,6 +63,18 @@ impl BufferPool {
     }
 
     /// Returns a buffer back to the pool for reuse.
+    #[cfg(feature = "std")]
+    pub fn release(&self, mut buffer: Vec<f32>) {
+        buffer.clear();
+        if let Ok(mut buffers) = self.buffers.lock() {
+            if buffers.len() < self.max_capacity {
+                buffers.push(buffer);
+            }
+        }
+    }
+
+    /// Returns a buffer back to the pool for reuse.
+    #[cfg(not(feature = "std"))]
     pub fn release(&mut self, mut buffer: Vec<f32>) {
         buffer.clear();
         if self.buffers.len() < self.max_capacity {
@@ -34,6 +82,14 @@ impl BufferPool {
         }
     }
 
+    #[cfg(feature = "std")]
+    pub fn reset(&self) {
+        if let Ok(mut buffers) = self.buffers.lock() {
+            buffers.clear();
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
     pub fn reset(&mut self) {
         self.buffers.clear();
     }
@@ -0,0 +1,202 @@
+use crate::agp_interface::DetectionRecord;
+use crate::prelude::{
+    ProcessingStage, StageConfig, StageError, StageInput, StageMetadata, StageOutput, StageResult,
+};
+use crate::telemetry::log::LogManager;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use libm::{log10f, powf};
+use ndarray::Array2;
+
+/// Cell-averaging CFAR (constant false-alarm rate) detector run over a
+/// range-Doppler magnitude map, replacing the scenario-metadata-driven
+/// padding `Runner` used to fall back on when too few real detections
+/// survived the clutter stage.
+pub struct CfarStage {
+    config: Option<StageConfig>,
+    logger: LogManager,
+}
+
+impl CfarStage {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            logger: LogManager::new(),
+        }
+    }
+}
+
+impl Default for CfarStage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Mean magnitude of the training cells surrounding `(doppler_bin,
+/// range_bin)`, excluding the guard band (and the cell under test itself).
+/// Training/guard windows are clamped at the map edges, which shrinks the
+/// effective training-cell count `T` rather than wrapping or padding.
+fn estimate_noise_floor(
+    magnitudes: &Array2<f32>,
+    doppler_bin: usize,
+    range_bin: usize,
+    guard: usize,
+    training: usize,
+) -> (f32, usize) {
+    let (doppler_bins, range_bins) = magnitudes.dim();
+    let window = guard + training;
+
+    let doppler_lo = doppler_bin.saturating_sub(window);
+    let doppler_hi = (doppler_bin + window).min(doppler_bins - 1);
+    let range_lo = range_bin.saturating_sub(window);
+    let range_hi = (range_bin + window).min(range_bins - 1);
+
+    let mut sum = 0.0f32;
+    let mut count = 0usize;
+    for d in doppler_lo..=doppler_hi {
+        let doppler_dist = (d as isize - doppler_bin as isize).unsigned_abs();
+        for r in range_lo..=range_hi {
+            let range_dist = (r as isize - range_bin as isize).unsigned_abs();
+            if doppler_dist <= guard && range_dist <= guard {
+                continue;
+            }
+            sum += magnitudes[[d, r]];
+            count += 1;
+        }
+    }
+
+    if count == 0 {
+        (0.0, 0)
+    } else {
+        (sum / count as f32, count)
+    }
+}
+
+impl ProcessingStage for CfarStage {
+    fn initialize(&mut self, config: &StageConfig) -> StageResult<()> {
+        self.config = Some(config.clone());
+        Ok(())
+    }
+
+    fn execute(&mut self, input: StageInput) -> StageResult<StageOutput> {
+        let config = self
+            .config
+            .as_ref()
+            .ok_or_else(|| StageError::Internal("stage not initialized".into()))?;
+
+        let (doppler_bins, range_bins) = input.matrix.dim();
+        if doppler_bins == 0 || range_bins == 0 {
+            return Err(StageError::InvalidInput("no samples to scan".into()));
+        }
+
+        let guard = config.cfar_guard_cells;
+        let training = config.cfar_training_cells.max(1);
+        let false_alarm_rate = config.cfar_false_alarm_rate;
+
+        let magnitudes = input.matrix.mapv(|value| value.norm());
+        let timestamp = input.timestamp.unwrap_or(0.0);
+        let half_doppler = (doppler_bins as f32 / 2.0).max(1.0);
+
+        let mut detection_records = Vec::new();
+        for doppler_bin in 0..doppler_bins {
+            for range_bin in 0..range_bins {
+                let cut = magnitudes[[doppler_bin, range_bin]];
+                let (noise_floor, training_count) =
+                    estimate_noise_floor(&magnitudes, doppler_bin, range_bin, guard, training);
+                if training_count == 0 || noise_floor <= 0.0 {
+                    continue;
+                }
+
+                let alpha = training_count as f32
+                    * (powf(false_alarm_rate, -1.0 / training_count as f32) - 1.0);
+                let threshold = alpha * noise_floor;
+                if cut <= threshold {
+                    continue;
+                }
+
+                let range = config.range_bins.max(1) as f32 * (range_bin as f32 / range_bins as f32);
+                let doppler = (doppler_bin as f32 - half_doppler) / half_doppler;
+                let snr = 10.0 * log10f(cut / noise_floor);
+                let bearing_deg = (range_bin as f32 / range_bins as f32) * 360.0;
+
+                detection_records.push(DetectionRecord::new(
+                    timestamp, range, doppler, snr, bearing_deg, 0.0,
+                ));
+            }
+        }
+
+        let detection_count = detection_records.len();
+        self.logger
+            .record(&format!("CfarStage detections {}", detection_count));
+
+        let metadata = StageMetadata {
+            detection_count: Some(detection_count),
+            detection_records,
+            notes: vec![format!(
+                "CFAR G={guard} T={training} Pfa={false_alarm_rate:.1e}"
+            )],
+            ..Default::default()
+        };
+
+        Ok(StageOutput {
+            matrix: input.matrix,
+            metadata,
+        })
+    }
+
+    fn cleanup(&mut self) {
+        self.config = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_complex::Complex32;
+
+    fn config() -> StageConfig {
+        StageConfig {
+            taps: 1,
+            range_bins: 16,
+            doppler_bins: 16,
+            cfar_guard_cells: 1,
+            cfar_training_cells: 3,
+            cfar_false_alarm_rate: 1e-2,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn cfar_stage_flags_isolated_peak_above_noise_floor() {
+        let mut stage = CfarStage::new();
+        stage.initialize(&config()).unwrap();
+
+        let mut matrix = Array2::<Complex32>::from_elem((16, 16), Complex32::new(0.1, 0.0));
+        matrix[[8, 8]] = Complex32::new(50.0, 0.0);
+
+        let input = StageInput {
+            matrix,
+            timestamp: Some(0.0),
+        };
+        let output = stage.execute(input).unwrap();
+        assert_eq!(output.metadata.detection_count.unwrap(), 1);
+        assert_eq!(output.metadata.detection_records[0].range, 8.0);
+        stage.cleanup();
+    }
+
+    #[test]
+    fn cfar_stage_reports_no_detections_in_uniform_noise() {
+        let mut stage = CfarStage::new();
+        stage.initialize(&config()).unwrap();
+
+        let matrix = Array2::<Complex32>::from_elem((16, 16), Complex32::new(0.1, 0.0));
+        let input = StageInput {
+            matrix,
+            timestamp: Some(0.0),
+        };
+        let output = stage.execute(input).unwrap();
+        assert_eq!(output.metadata.detection_count.unwrap(), 0);
+        stage.cleanup();
+    }
+}
@@ -3,20 +3,20 @@ use crate::math::stats::StatsHelper;
 use crate::prelude::{
     ProcessingStage, StageConfig, StageError, StageInput, StageMetadata, StageOutput, StageResult,
 };
-use crate::processing::buffer_pool::BufferPool;
 use crate::telemetry::log::LogManager;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// Clutter/detection stage that wraps the final processing step.
 pub struct ClutterStage {
-    pool: BufferPool,
     config: Option<StageConfig>,
     logger: LogManager,
 }
 
 impl ClutterStage {
-    pub fn new(pool_size: usize) -> Self {
+    pub fn new(_pool_size: usize) -> Self {
         Self {
-            pool: BufferPool::with_capacity(pool_size),
             config: None,
             logger: LogManager::new(),
         }
@@ -35,39 +35,28 @@ impl ProcessingStage for ClutterStage {
             .as_ref()
             .ok_or_else(|| StageError::Internal("stage not initialized".into()))?;
 
-        if input.samples.is_empty() {
+        let (doppler_bins, range_bins) = input.matrix.dim();
+        if doppler_bins == 0 || range_bins == 0 {
             return Err(StageError::InvalidInput("no samples to scan".into()));
         }
 
-        let mut buffer = self.pool.checkout(input.samples.len())?;
-        buffer.copy_from_slice(&input.samples);
+        let magnitudes: Vec<f32> = input.matrix.iter().map(|value| value.norm()).collect();
+        let threshold = StatsHelper::rms(&magnitudes) * 1.2;
 
-        let threshold = StatsHelper::rms(&buffer) * 1.2;
-        let buffer_len = buffer.len() as f32;
-        let half_bins = if buffer_len > 0.0 {
-            buffer_len / 2.0
-        } else {
-            0.0
-        };
-
-        let mut detection_records = Vec::new();
         let timestamp = input.timestamp.unwrap_or(0.0);
         let range_scale = config.range_bins as f32;
+        let half_doppler = (doppler_bins as f32 / 2.0).max(1.0);
 
-        for (idx, &value) in buffer.iter().enumerate() {
-            if value > threshold {
-                let range = if buffer_len > 0.0 {
-                    range_scale * (idx as f32 / buffer_len)
-                } else {
-                    0.0
-                };
-                let doppler = if half_bins > 0.0 {
-                    (idx as f32 - half_bins) / half_bins
-                } else {
-                    0.0
-                };
-                let snr = value / threshold;
-                detection_records.push(DetectionRecord::new(timestamp, range, doppler, snr));
+        let mut detection_records = Vec::new();
+        for ((doppler_bin, range_bin), value) in input.matrix.indexed_iter() {
+            let magnitude = value.norm();
+            if magnitude > threshold {
+                let range = range_scale * (range_bin as f32 / range_bins as f32);
+                let doppler = (doppler_bin as f32 - half_doppler) / half_doppler;
+                let snr = magnitude / threshold;
+                detection_records.push(DetectionRecord::new(
+                    timestamp, range, doppler, snr, 0.0, 0.0,
+                ));
             }
         }
 
@@ -83,13 +72,12 @@ impl ProcessingStage for ClutterStage {
         };
 
         Ok(StageOutput {
-            samples: buffer,
+            matrix: input.matrix,
             metadata,
         })
     }
 
     fn cleanup(&mut self) {
-        self.pool.reset();
         self.config = None;
     }
 }
@@ -104,14 +92,13 @@ mod tests {
         let config = StageConfig {
             taps: 1,
             range_bins: 4,
-            doppler_bins: 4,
+            doppler_bins: 1,
+            ..Default::default()
         };
 
         stage.initialize(&config).unwrap();
-        let input = StageInput {
-            samples: vec![0.1, 20.0, 0.2, 20.0],
-            timestamp: Some(0.0),
-        };
+        let input =
+            StageInput::from_real_samples(&[0.1, 20.0, 0.2, 20.0], 1, 4, Some(0.0)).unwrap();
 
         let output = stage.execute(input).unwrap();
         assert!(output.metadata.detection_count.unwrap_or(0) >= 2);
@@ -1,54 +1,148 @@
 use crate::math::fft::FftHelper;
 use crate::math::stats::StatsHelper;
 use crate::prelude::{
-    ProcessingStage, StageConfig, StageError, StageInput, StageMetadata, StageOutput, StageResult,
+    ComputeBackend, ProcessingStage, StageConfig, StageError, StageInput, StageMetadata,
+    StageOutput, StageResult,
 };
-use crate::processing::buffer_pool::BufferPool;
 use crate::telemetry::log::LogManager;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use libm::cosf;
+use ndarray::Array2;
+use num_complex::Complex32;
 
-/// Doppler-stage performing centroid correction and FFT-based power estimation.
+#[cfg(feature = "gpu")]
+use crate::compute::GpuDopplerBackend;
+
+/// Doppler-stage applying a slow-time taper and a transform along the
+/// pulses axis, one range bin at a time, to produce a `(doppler_bins,
+/// range_bins)` range-Doppler matrix. Runs on the CPU by default; with the
+/// `gpu` feature and `StageConfig::backend == ComputeBackend::Gpu`, the
+/// transform runs as a batched compute shader instead, falling back to the
+/// CPU path whenever no adapter is available.
+///
+/// Needs an `FftHelper` backend to exist at all (see `FftHelper::with_backend_preference`),
+/// so unlike the rest of `processing` this stage is only compiled in with
+/// `std` or `gpu` — see `processing`'s module docs.
 pub struct DopplerStage {
-    pool: BufferPool,
     config: Option<StageConfig>,
     fft: Option<FftHelper>,
+    #[cfg(feature = "gpu")]
+    gpu: Option<GpuDopplerBackend>,
     logger: LogManager,
 }
 
 impl DopplerStage {
-    pub fn new(pool_size: usize) -> Self {
+    pub fn new(_pool_size: usize) -> Self {
         Self {
-            pool: BufferPool::with_capacity(pool_size),
             config: None,
             fft: None,
+            #[cfg(feature = "gpu")]
+            gpu: None,
             logger: LogManager::new(),
         }
     }
 }
 
+/// Symmetric Hamming window of length `n`, tapering the slow-time (pulses)
+/// axis before the Doppler FFT so a strong return's spectral sidelobes don't
+/// leak across Doppler bins.
+fn hamming_window(n: usize) -> Vec<f32> {
+    if n <= 1 {
+        return vec![1.0; n];
+    }
+    (0..n)
+        .map(|i| 0.54 - 0.46 * cosf(2.0 * core::f32::consts::PI * i as f32 / (n - 1) as f32))
+        .collect()
+}
+
 impl ProcessingStage for DopplerStage {
     fn initialize(&mut self, config: &StageConfig) -> StageResult<()> {
         self.config = Some(config.clone());
-        self.fft = Some(FftHelper::new(config.doppler_bins.max(1)));
+        self.fft = Some(FftHelper::with_backend_preference(
+            config.doppler_bins.max(1),
+            config.backend,
+        ));
+
+        #[cfg(feature = "gpu")]
+        {
+            self.gpu = None;
+            if config.backend == ComputeBackend::Gpu {
+                match GpuDopplerBackend::new(config.doppler_bins.max(1)) {
+                    Ok(backend) => self.gpu = Some(backend),
+                    Err(err) => self.logger.record(&format!(
+                        "GPU Doppler backend unavailable, falling back to CPU: {err}"
+                    )),
+                }
+            }
+        }
+
         Ok(())
     }
 
     fn execute(&mut self, input: StageInput) -> StageResult<StageOutput> {
-        if input.samples.is_empty() {
+        let (pulses, range_bins) = input.matrix.dim();
+        if pulses == 0 || range_bins == 0 {
             return Err(StageError::InvalidInput("no samples provided".into()));
         }
 
+        let taper = hamming_window(pulses);
+
+        #[cfg(feature = "gpu")]
+        if let Some(gpu) = &self.gpu {
+            match gpu.transform(&input.matrix, &taper) {
+                Ok(spectrum) => return self.finish(spectrum),
+                Err(err) => self.logger.record(&format!(
+                    "GPU Doppler transform failed, falling back to CPU: {err}"
+                )),
+            }
+        }
+
+        let config = self
+            .config
+            .as_ref()
+            .ok_or_else(|| StageError::Internal("stage not initialized".into()))?;
         let fft = self
             .fft
             .as_mut()
             .ok_or_else(|| StageError::Internal("FFT not configured".into()))?;
 
-        let transformed = fft.forward(&input.samples);
-        let magnitudes: Vec<f32> = transformed.iter().map(|c| c.norm()).collect();
+        let doppler_bins = config.doppler_bins.max(1);
+        let mut spectrum = Array2::<Complex32>::zeros((doppler_bins, range_bins));
+        for range_bin in 0..range_bins {
+            let tapered: Vec<Complex32> = input
+                .matrix
+                .column(range_bin)
+                .iter()
+                .zip(taper.iter())
+                .map(|(sample, window_value)| sample * window_value)
+                .collect();
+            let transformed = fft.forward_complex(&tapered);
+            for (doppler_bin, value) in transformed.into_iter().enumerate() {
+                spectrum[[doppler_bin, range_bin]] = value;
+            }
+        }
 
-        let mut buffer = self.pool.checkout(magnitudes.len())?;
-        buffer.clone_from_slice(&magnitudes);
+        self.finish(spectrum)
+    }
+
+    fn cleanup(&mut self) {
+        self.config = None;
+        self.fft = None;
+        #[cfg(feature = "gpu")]
+        {
+            self.gpu = None;
+        }
+    }
+}
 
-        let rms = StatsHelper::rms(&buffer);
+impl DopplerStage {
+    /// Shared tail for both the CPU and GPU paths: derives telemetry from
+    /// the finished spectrum and wraps it as a `StageOutput`.
+    fn finish(&self, spectrum: Array2<Complex32>) -> StageResult<StageOutput> {
+        let magnitudes: Vec<f32> = spectrum.iter().map(|value| value.norm()).collect();
+        let rms = StatsHelper::rms(&magnitudes);
         self.logger.record(&format!("Doppler RMS {:.4}", rms));
 
         let metadata = StageMetadata {
@@ -57,16 +151,10 @@ impl ProcessingStage for DopplerStage {
         };
 
         Ok(StageOutput {
-            samples: buffer,
+            matrix: spectrum,
             metadata,
         })
     }
-
-    fn cleanup(&mut self) {
-        self.pool.reset();
-        self.config = None;
-        self.fft = None;
-    }
 }
 
 #[cfg(test)]
@@ -74,23 +162,28 @@ mod tests {
     use super::*;
 
     #[test]
-    fn doppler_stage_returns_magnitude_sequence() {
+    fn doppler_stage_returns_range_doppler_matrix() {
         let mut stage = DopplerStage::new(8);
         let config = StageConfig {
             taps: 1,
             range_bins: 4,
             doppler_bins: 8,
+            ..Default::default()
         };
 
         stage.initialize(&config).unwrap();
-        let input = StageInput {
-            samples: vec![1.0, 0.0, 0.0, 0.0],
-            timestamp: Some(0.0),
-        };
+        let input = StageInput::from_real_samples(&[1.0, 0.0, 0.0, 0.0], 1, 4, Some(0.0)).unwrap();
 
         let output = stage.execute(input).unwrap();
-        assert_eq!(output.samples.len(), 8);
+        assert_eq!(output.matrix.dim(), (8, 4));
         assert!(output.metadata.notes[0].starts_with("doppler RMS"));
         stage.cleanup();
     }
+
+    #[test]
+    fn hamming_window_tapers_edges_below_peak() {
+        let window = hamming_window(8);
+        assert_eq!(window.len(), 8);
+        assert!(window[0] < window[4]);
+    }
 }
@@ -1,9 +1,20 @@
+//! `buffer_pool`, `cfar`, `clutter`, and `range` only need `alloc` (`ndarray`
+//! builds without its `std` feature, and their float math goes through
+//! `libm` rather than the host's) and build on embedded `no_std`
+//! front-ends. `doppler` is the exception: it needs a working `FftHelper`
+//! backend, and `math::fft` only has one under `std` or `gpu` — see
+//! `DopplerStage`'s docs.
+
 pub mod buffer_pool;
+pub mod cfar;
 pub mod clutter;
+#[cfg(any(feature = "std", feature = "gpu"))]
 pub mod doppler;
 pub mod range;
 
 pub use buffer_pool::BufferPool;
+pub use cfar::CfarStage;
 pub use clutter::ClutterStage;
+#[cfg(any(feature = "std", feature = "gpu"))]
 pub use doppler::DopplerStage;
 pub use range::RangeStage;
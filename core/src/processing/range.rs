@@ -1,22 +1,37 @@
 use crate::math::stats::StatsHelper;
 use crate::prelude::{
-    ProcessingStage, StageConfig, StageError, StageInput, StageMetadata, StageOutput, StageResult,
+    ComputeBackend, ProcessingStage, StageConfig, StageError, StageInput, StageMetadata,
+    StageOutput, StageResult,
 };
-use crate::processing::buffer_pool::BufferPool;
 use crate::telemetry::log::LogManager;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use libm::sqrtf;
+use ndarray::{Array2, Axis};
+use num_complex::Complex32;
 
-/// Range-processing stage that mirrors the legacy CPI correction / range compression.
+#[cfg(feature = "gpu")]
+use crate::compute::GpuPowerProfileBackend;
+
+/// Range-processing stage that mirrors the legacy CPI correction / range
+/// compression. Runs on the CPU by default; with the `gpu` feature and
+/// `StageConfig::backend == ComputeBackend::Gpu`, the per-range-bin power
+/// profile is computed as a batched compute shader instead, falling back
+/// to the CPU path whenever no adapter is available.
 pub struct RangeStage {
-    pool: BufferPool,
     config: Option<StageConfig>,
+    #[cfg(feature = "gpu")]
+    gpu: Option<GpuPowerProfileBackend>,
     logger: LogManager,
 }
 
 impl RangeStage {
-    pub fn new(pool_size: usize) -> Self {
+    pub fn new(_pool_size: usize) -> Self {
         Self {
-            pool: BufferPool::with_capacity(pool_size),
             config: None,
+            #[cfg(feature = "gpu")]
+            gpu: None,
             logger: LogManager::new(),
         }
     }
@@ -25,6 +40,20 @@ impl RangeStage {
 impl ProcessingStage for RangeStage {
     fn initialize(&mut self, config: &StageConfig) -> StageResult<()> {
         self.config = Some(config.clone());
+
+        #[cfg(feature = "gpu")]
+        {
+            self.gpu = None;
+            if config.backend == ComputeBackend::Gpu {
+                match GpuPowerProfileBackend::new() {
+                    Ok(backend) => self.gpu = Some(backend),
+                    Err(err) => self.logger.record(&format!(
+                        "GPU power-profile backend unavailable, falling back to CPU: {err}"
+                    )),
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -34,19 +63,50 @@ impl ProcessingStage for RangeStage {
             .as_ref()
             .ok_or_else(|| StageError::Internal("stage not initialized".into()))?;
 
-        let expected = config.range_bins * config.taps;
-        if input.samples.len() < expected {
+        let (pulses, range_bins) = input.matrix.dim();
+        let expected_range_bins = config.range_bins.max(1);
+        if range_bins != expected_range_bins {
             return Err(StageError::InvalidInput(format!(
-                "expected at least {} samples",
-                expected
+                "expected {expected_range_bins} range bins, got {range_bins}"
             )));
         }
+        if pulses == 0 {
+            return Err(StageError::InvalidInput("no pulses to range-compress".into()));
+        }
 
-        let mut payload = self.pool.checkout(config.range_bins)?;
-        payload.copy_from_slice(&input.samples[..config.range_bins]);
+        #[cfg(feature = "gpu")]
+        if let Some(gpu) = &self.gpu {
+            match gpu.power_profile(&input.matrix) {
+                Ok(power_profile) => return self.finish(input.matrix, power_profile),
+                Err(err) => self.logger.record(&format!(
+                    "GPU power profile failed, falling back to CPU: {err}"
+                )),
+            }
+        }
+
+        // One RMS per range bin, across every pulse in the CPI, rather than
+        // the single leading pulse the flat-`Vec<f32>` pipeline used to
+        // keep after discarding the rest of the matrix.
+        let rms_per_range_bin = StatsHelper::rms_per_axis(&input.matrix, Axis(1));
+        let power_profile: Vec<f32> = rms_per_range_bin.iter().map(|rms| rms * rms).collect();
+        self.finish(input.matrix, power_profile)
+    }
+
+    fn cleanup(&mut self) {
+        self.config = None;
+        #[cfg(feature = "gpu")]
+        {
+            self.gpu = None;
+        }
+    }
+}
 
-        let power_profile = payload.iter().map(|v| v * v).collect::<Vec<_>>();
-        let rms = StatsHelper::rms(&payload);
+impl RangeStage {
+    /// Shared tail for both the CPU and GPU paths: derives telemetry from
+    /// the finished power profile and wraps it as a `StageOutput`.
+    fn finish(&self, matrix: Array2<Complex32>, power_profile: Vec<f32>) -> StageResult<StageOutput> {
+        let rms_per_range_bin: Vec<f32> = power_profile.iter().map(|power| sqrtf(*power)).collect();
+        let rms = StatsHelper::rms(&rms_per_range_bin);
         self.logger.record(&format!("RangeStage RMS {:.4}", rms));
 
         let metadata = StageMetadata {
@@ -55,21 +115,14 @@ impl ProcessingStage for RangeStage {
             ..Default::default()
         };
 
-        Ok(StageOutput {
-            samples: payload,
-            metadata,
-        })
-    }
-
-    fn cleanup(&mut self) {
-        self.pool.reset();
-        self.config = None;
+        Ok(StageOutput { matrix, metadata })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use num_complex::Complex32;
 
     #[test]
     fn range_stage_computes_power_profile() {
@@ -78,19 +131,37 @@ mod tests {
             taps: 1,
             range_bins: 4,
             doppler_bins: 2,
+            ..Default::default()
         };
 
         stage.initialize(&config).unwrap();
-        let input = StageInput {
-            samples: vec![1.0, 2.0, 3.0, 4.0],
-            timestamp: Some(0.0),
-        };
+        let input = StageInput::from_real_samples(&[1.0, 2.0, 3.0, 4.0], 1, 4, Some(0.0)).unwrap();
 
         let output = stage.execute(input).unwrap();
         assert_eq!(
             output.metadata.power_profile.unwrap(),
             vec![1.0, 4.0, 9.0, 16.0]
         );
+        assert_eq!(output.matrix.dim(), (1, 4));
+        stage.cleanup();
+    }
+
+    #[test]
+    fn range_stage_rejects_mismatched_range_bins() {
+        let mut stage = RangeStage::new(4);
+        let config = StageConfig {
+            taps: 1,
+            range_bins: 4,
+            doppler_bins: 2,
+            ..Default::default()
+        };
+        stage.initialize(&config).unwrap();
+
+        let input = StageInput {
+            matrix: ndarray::Array2::from_elem((1, 3), Complex32::new(0.0, 0.0)),
+            timestamp: Some(0.0),
+        };
+        assert!(stage.execute(input).is_err());
         stage.cleanup();
     }
 }
@@ -1,5 +1,11 @@
+#[cfg(feature = "std")]
 use log::info;
 
+/// Records telemetry lines (RMS, detection counts, stage notes) to whichever
+/// sink fits the build: the `log`/string path on hosted builds, or
+/// structured `defmt` frames over RTT on embedded targets with the `defmt`
+/// feature on. Only one of the two is compiled in, so `record` never pays
+/// for formatting it won't use.
 pub struct LogManager;
 
 impl LogManager {
@@ -7,9 +13,18 @@ impl LogManager {
         Self
     }
 
+    #[cfg(feature = "defmt")]
+    pub fn record(&self, message: &str) {
+        defmt::info!("{=str}", message);
+    }
+
+    #[cfg(all(feature = "std", not(feature = "defmt")))]
     pub fn record(&self, message: &str) {
         info!("{}", message);
     }
+
+    #[cfg(not(any(feature = "std", feature = "defmt")))]
+    pub fn record(&self, _message: &str) {}
 }
 
 impl Default for LogManager {
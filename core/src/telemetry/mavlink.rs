@@ -0,0 +1,204 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Ground-derived platform state kept current by a MAVLink link, read by the
+/// generator on every burst so synthetic PRIs track the real sensor's
+/// motion instead of the fixed defaults in `GeneratorConfig`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlatformState {
+    pub latitude_deg: f64,
+    pub longitude_deg: f64,
+    pub altitude_m: f32,
+    pub ground_speed_kmh: f32,
+    pub heading_deg: f32,
+    pub roll_deg: f32,
+    pub pitch_deg: f32,
+    pub yaw_deg: f32,
+    /// Time (seconds) of the message that last updated this state; `connect`
+    /// fills it from the flight controller's `time_boot_ms`, i.e. seconds
+    /// since the controller booted.
+    pub timestamp: f64,
+}
+
+impl Default for PlatformState {
+    fn default() -> Self {
+        Self {
+            latitude_deg: 0.0,
+            longitude_deg: 0.0,
+            altitude_m: 0.0,
+            ground_speed_kmh: 0.0,
+            heading_deg: 0.0,
+            roll_deg: 0.0,
+            pitch_deg: 0.0,
+            yaw_deg: 0.0,
+            timestamp: 0.0,
+        }
+    }
+}
+
+/// Shared handle to the latest `PlatformState`, cloned into the connection
+/// thread and into every caller that wants to read the current snapshot.
+#[derive(Debug, Clone)]
+pub struct PlatformStateHandle(Arc<Mutex<PlatformState>>);
+
+impl PlatformStateHandle {
+    pub fn new(initial: PlatformState) -> Self {
+        Self(Arc::new(Mutex::new(initial)))
+    }
+
+    pub fn snapshot(&self) -> PlatformState {
+        self.0.lock().map(|state| *state).unwrap_or_default()
+    }
+
+    fn set(&self, state: PlatformState) {
+        if let Ok(mut guard) = self.0.lock() {
+            *guard = state;
+        }
+    }
+}
+
+impl Default for PlatformStateHandle {
+    fn default() -> Self {
+        Self::new(PlatformState::default())
+    }
+}
+
+/// Where to dial a MAVLink stream from: a UDP endpoint (the common case for
+/// SITL and companion computers) or a serial port (a flight controller
+/// wired directly to the host).
+#[derive(Debug, Clone)]
+pub enum MavlinkSource {
+    Udp(String),
+    Serial { path: String, baud: u32 },
+}
+
+impl MavlinkSource {
+    /// The connection string the `mavlink` crate's `connect` expects.
+    fn connection_string(&self) -> String {
+        match self {
+            MavlinkSource::Udp(addr) => format!("udpin:{addr}"),
+            MavlinkSource::Serial { path, baud } => format!("serial:{path}:{baud}"),
+        }
+    }
+}
+
+/// Decodes a `GLOBAL_POSITION_INT` message (lat/lon in 1e7 degrees, altitude
+/// in millimeters, velocity components in cm/s) into `state`. `hdg_cdeg` is
+/// the vehicle's compass heading in centidegrees, or `u16::MAX` when the
+/// flight controller doesn't know it — in that case the heading is derived
+/// from the ground-track of `vx`/`vy` instead.
+pub fn apply_global_position_int(
+    state: &mut PlatformState,
+    lat_e7: i32,
+    lon_e7: i32,
+    alt_mm: i32,
+    vx_cm_s: i16,
+    vy_cm_s: i16,
+    hdg_cdeg: u16,
+    timestamp: f64,
+) {
+    state.latitude_deg = lat_e7 as f64 / 1e7;
+    state.longitude_deg = lon_e7 as f64 / 1e7;
+    state.altitude_m = alt_mm as f32 / 1000.0;
+
+    let vx_ms = vx_cm_s as f32 / 100.0;
+    let vy_ms = vy_cm_s as f32 / 100.0;
+    let ground_speed_ms = (vx_ms * vx_ms + vy_ms * vy_ms).sqrt();
+    state.ground_speed_kmh = ground_speed_ms * 3.6;
+
+    state.heading_deg = if hdg_cdeg == u16::MAX {
+        vy_ms.atan2(vx_ms).to_degrees().rem_euclid(360.0)
+    } else {
+        hdg_cdeg as f32 / 100.0
+    };
+
+    state.timestamp = timestamp;
+}
+
+/// Decodes an `ATTITUDE` message (roll/pitch/yaw in radians) into `state`.
+pub fn apply_attitude(state: &mut PlatformState, roll_rad: f32, pitch_rad: f32, yaw_rad: f32) {
+    state.roll_deg = roll_rad.to_degrees();
+    state.pitch_deg = pitch_rad.to_degrees();
+    state.yaw_deg = yaw_rad.to_degrees();
+}
+
+/// Opens `source`, spawns a background thread that decodes
+/// `GLOBAL_POSITION_INT` and `ATTITUDE` messages as they arrive, and returns
+/// a handle the generator can poll for the latest `PlatformState`. The
+/// thread runs for the lifetime of the process; a dropped connection is
+/// reconnected automatically rather than ending the thread.
+pub fn connect(source: MavlinkSource) -> anyhow::Result<PlatformStateHandle> {
+    let handle = PlatformStateHandle::default();
+    let address = source.connection_string();
+    let thread_handle = handle.clone();
+
+    thread::spawn(move || loop {
+        match mavlink::connect::<mavlink::common::MavMessage>(&address) {
+            Ok(connection) => loop {
+                match connection.recv() {
+                    Ok((_header, mavlink::common::MavMessage::GLOBAL_POSITION_INT(data))) => {
+                        let mut state = thread_handle.snapshot();
+                        apply_global_position_int(
+                            &mut state,
+                            data.lat,
+                            data.lon,
+                            data.alt,
+                            data.vx,
+                            data.vy,
+                            data.hdg,
+                            data.time_boot_ms as f64 / 1000.0,
+                        );
+                        thread_handle.set(state);
+                    }
+                    Ok((_header, mavlink::common::MavMessage::ATTITUDE(data))) => {
+                        let mut state = thread_handle.snapshot();
+                        apply_attitude(&mut state, data.roll, data.pitch, data.yaw);
+                        thread_handle.set(state);
+                    }
+                    Ok(_) => continue,
+                    Err(err) => {
+                        eprintln!("[MAVLink] connection error, reconnecting: {err}");
+                        break;
+                    }
+                }
+            },
+            Err(err) => {
+                eprintln!("[MAVLink] failed to open {address}: {err}");
+            }
+        }
+        thread::sleep(std::time::Duration::from_secs(2));
+    });
+
+    Ok(handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn global_position_int_converts_units_and_derives_speed() {
+        let mut state = PlatformState::default();
+        apply_global_position_int(&mut state, 473_977_420, 85_455_900, 8_200_000, 3000, 0, 9000, 12.5);
+        assert_eq!(state.latitude_deg, 47.397742);
+        assert_eq!(state.altitude_m, 8200.0);
+        assert_eq!(state.ground_speed_kmh, 108.0);
+        assert_eq!(state.heading_deg, 90.0);
+        assert_eq!(state.timestamp, 12.5);
+    }
+
+    #[test]
+    fn unknown_heading_sentinel_falls_back_to_ground_track() {
+        let mut state = PlatformState::default();
+        apply_global_position_int(&mut state, 0, 0, 0, 0, 1000, u16::MAX, 0.0);
+        assert_eq!(state.heading_deg, 90.0);
+    }
+
+    #[test]
+    fn attitude_converts_radians_to_degrees() {
+        let mut state = PlatformState::default();
+        apply_attitude(&mut state, std::f32::consts::PI, 0.0, std::f32::consts::FRAC_PI_2);
+        assert_eq!(state.roll_deg.round(), 180.0);
+        assert_eq!(state.yaw_deg.round(), 90.0);
+    }
+}
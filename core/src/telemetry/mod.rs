@@ -1,5 +1,18 @@
 pub mod log;
+// `mavlink` and `mqtt` dial real sockets/serial ports/brokers, and `mqtt`
+// pulls in `metrics` for its periodic snapshots — none of that exists on an
+// embedded front-end, so all three stay behind `std`.
+#[cfg(feature = "std")]
+pub mod mavlink;
+#[cfg(feature = "std")]
 pub mod metrics;
+#[cfg(feature = "std")]
+pub mod mqtt;
 
 pub use log::LogManager;
+#[cfg(feature = "std")]
+pub use mavlink::{MavlinkSource, PlatformState, PlatformStateHandle};
+#[cfg(feature = "std")]
 pub use metrics::MetricsRecorder;
+#[cfg(feature = "std")]
+pub use mqtt::{MqttConfig, MqttPublisher};
@@ -0,0 +1,166 @@
+use crate::agp_interface::DetectionRecord;
+use crate::telemetry::metrics::MetricsRecorder;
+use anyhow::Context;
+use rumqttc::{Client, MqttOptions, QoS};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Topic a live dashboard subscribes to for the detection records from each
+/// `Runner::execute` call.
+pub const DETECTIONS_TOPIC: &str = "gmti/detections";
+/// Topic carrying the range power profile from each `Runner::execute` call.
+pub const POWER_TOPIC: &str = "gmti/power";
+/// Topic carrying the Doppler notes from each `Runner::execute` call.
+pub const DOPPLER_TOPIC: &str = "gmti/doppler";
+/// Topic carrying a `MetricsRecorder::snapshot` on a fixed cadence.
+pub const METRICS_TOPIC: &str = "gmti/metrics";
+
+/// Broker connection details for `MqttPublisher`, threaded through
+/// `WorkflowConfig` so a deployment can point at its own broker instead of a
+/// hard-coded one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MqttConfig {
+    /// e.g. "localhost:1883".
+    pub broker_url: String,
+    pub client_id: String,
+    pub qos: u8,
+    /// How often `MetricsRecorder::snapshot` is pushed to `gmti/metrics`, in seconds.
+    pub metrics_interval_secs: u64,
+}
+
+impl Default for MqttConfig {
+    fn default() -> Self {
+        Self {
+            broker_url: "localhost:1883".into(),
+            client_id: "gmti-core".into(),
+            qos: 0,
+            metrics_interval_secs: 5,
+        }
+    }
+}
+
+fn qos_from_level(level: u8) -> QoS {
+    match level {
+        1 => QoS::AtLeastOnce,
+        2 => QoS::ExactlyOnce,
+        _ => QoS::AtMostOnce,
+    }
+}
+
+fn split_broker_url(broker_url: &str) -> anyhow::Result<(String, u16)> {
+    let (host, port) = broker_url
+        .rsplit_once(':')
+        .with_context(|| format!("MQTT broker url `{broker_url}` is missing a port"))?;
+    let port: u16 = port
+        .parse()
+        .with_context(|| format!("MQTT broker url `{broker_url}` has a non-numeric port"))?;
+    Ok((host.to_string(), port))
+}
+
+/// Publishes detection records, power profiles, and Doppler notes to an MQTT
+/// broker after each `Runner::execute`, plus a `MetricsRecorder` snapshot on
+/// a fixed cadence, so a live dashboard can subscribe instead of polling the
+/// GUI bridge's HTTP routes.
+pub struct MqttPublisher {
+    client: Client,
+    qos: QoS,
+    metrics: Arc<MetricsRecorder>,
+}
+
+impl MqttPublisher {
+    /// Connects to `config.broker_url` and spawns the background thread
+    /// that drives the client's event loop and the cadence thread that
+    /// publishes `MetricsRecorder` snapshots to `gmti/metrics`.
+    pub fn connect(config: &MqttConfig) -> anyhow::Result<Self> {
+        let (host, port) = split_broker_url(&config.broker_url)?;
+        let mut options = MqttOptions::new(config.client_id.clone(), host, port);
+        options.set_keep_alive(Duration::from_secs(30));
+
+        let (client, mut event_loop) = Client::new(options, 16);
+        thread::spawn(move || loop {
+            if let Err(err) = event_loop.poll() {
+                eprintln!("[MQTT] event loop error, retrying: {err}");
+                thread::sleep(Duration::from_secs(1));
+            }
+        });
+
+        let metrics = Arc::new(MetricsRecorder::new());
+        let qos = qos_from_level(config.qos);
+
+        let cadence_client = client.clone();
+        let cadence_metrics = metrics.clone();
+        let interval = Duration::from_secs(config.metrics_interval_secs.max(1));
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+            let (processed, errors) = cadence_metrics.snapshot();
+            let body = json!({ "processed": processed, "errors": errors }).to_string();
+            if let Err(err) = cadence_client.publish(METRICS_TOPIC, qos, false, body) {
+                eprintln!("[MQTT] failed to publish metrics snapshot: {err}");
+            }
+        });
+
+        Ok(Self {
+            client,
+            qos,
+            metrics,
+        })
+    }
+
+    /// The recorder the cadence thread snapshots; `Runner` bumps its
+    /// processed count on each completed execution. Failed executions are
+    /// not recorded, so the error count stays 0.
+    pub fn metrics(&self) -> &MetricsRecorder {
+        &self.metrics
+    }
+
+    pub fn publish_detections(&self, records: &[DetectionRecord]) {
+        self.publish_json(DETECTIONS_TOPIC, records);
+    }
+
+    pub fn publish_power(&self, power_profile: &[f32]) {
+        self.publish_json(POWER_TOPIC, power_profile);
+    }
+
+    pub fn publish_doppler_notes(&self, notes: &[String]) {
+        self.publish_json(DOPPLER_TOPIC, notes);
+    }
+
+    fn publish_json<T: Serialize>(&self, topic: &str, value: T) {
+        match serde_json::to_vec(&value) {
+            Ok(body) => {
+                if let Err(err) = self.client.publish(topic, self.qos, false, body) {
+                    eprintln!("[MQTT] failed to publish to {topic}: {err}");
+                }
+            }
+            Err(err) => eprintln!("[MQTT] failed to serialize payload for {topic}: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_host_and_port() {
+        let (host, port) = split_broker_url("broker.local:1883").unwrap();
+        assert_eq!(host, "broker.local");
+        assert_eq!(port, 1883);
+    }
+
+    #[test]
+    fn rejects_url_without_port() {
+        assert!(split_broker_url("broker.local").is_err());
+    }
+
+    #[test]
+    fn maps_qos_levels() {
+        assert_eq!(qos_from_level(0), QoS::AtMostOnce);
+        assert_eq!(qos_from_level(1), QoS::AtLeastOnce);
+        assert_eq!(qos_from_level(2), QoS::ExactlyOnce);
+        assert_eq!(qos_from_level(9), QoS::AtMostOnce);
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Context;
 use gmticore::agp_interface::{PriAncillary, PriPayload, PriType, ScenarioMetadata};
+use gmticore::telemetry::PlatformState;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::f32::consts::PI;
@@ -27,6 +28,10 @@ pub struct GeneratorConfig {
     pub interference_db: f32,
     pub target_motion: String,
     pub timestamp_start: f64,
+    /// Live platform heading, in degrees, when a MAVLink feed is driving
+    /// this config (see `apply_platform_state`). `None` falls back to the
+    /// `target_motion`-derived bias `build_sample_vector` has always used.
+    pub heading_deg: Option<f32>,
 }
 
 impl Default for GeneratorConfig {
@@ -51,6 +56,7 @@ impl Default for GeneratorConfig {
             interference_db: -10.0,
             target_motion: "Cruise, gentle zig-zag".into(),
             timestamp_start: 0.0,
+            heading_deg: None,
         }
     }
 }
@@ -63,6 +69,16 @@ impl GeneratorConfig {
     fn normalized_range(&self) -> usize {
         self.range_bins.max(1)
     }
+
+    /// Pulls ground speed, altitude, heading, and message time from a live
+    /// MAVLink `PlatformState` into this config, so the next generated burst
+    /// reflects the real platform instead of the hard-coded defaults.
+    pub fn apply_platform_state(&mut self, state: &PlatformState) {
+        self.platform_velocity_kmh = state.ground_speed_kmh;
+        self.altitude_m = Some(state.altitude_m);
+        self.heading_deg = Some(state.heading_deg);
+        self.timestamp_start = state.timestamp;
+    }
 }
 
 fn build_sample_vector(config: &GeneratorConfig) -> anyhow::Result<Vec<f32>> {
@@ -78,7 +94,13 @@ fn build_sample_vector(config: &GeneratorConfig) -> anyhow::Result<Vec<f32>> {
     let motion_signature = config.target_motion.bytes().fold(0u64, |acc, byte| {
         acc.wrapping_mul(31).wrapping_add(byte as u64)
     });
-    let motion_bias = (motion_signature as f32 % 360.0).to_radians();
+    // A live MAVLink heading takes over the motion bias entirely, so the
+    // synthetic clutter Doppler tracks where the platform is actually
+    // pointed rather than the scenario's scripted `target_motion` text.
+    let motion_bias = config
+        .heading_deg
+        .map(|heading| heading.to_radians())
+        .unwrap_or_else(|| (motion_signature as f32 % 360.0).to_radians());
     let snr_linear = 10f32.powf(config.snr_target_db / 20.0);
     let interference_amplitude = 10f32.powf(config.interference_db / 20.0);
     let speed_factor = (config.platform_velocity_kmh / 500.0).min(3.0);
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+use warp::Filter;
+
+/// A single accepted API key, optionally bounded by a validity window
+/// expressed as Unix timestamps (seconds).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyEntry {
+    pub key: String,
+    #[serde(default)]
+    pub not_before: Option<f64>,
+    #[serde(default)]
+    pub not_after: Option<f64>,
+}
+
+impl ApiKeyEntry {
+    fn is_valid_at(&self, now: f64) -> bool {
+        self.not_before.map_or(true, |nb| now >= nb) && self.not_after.map_or(true, |na| now <= na)
+    }
+}
+
+/// Accepted API keys for the GUI bridge's mutating routes. An empty key set
+/// leaves the bridge open, matching today's no-auth behavior.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiKeyConfig {
+    #[serde(default)]
+    pub keys: Vec<ApiKeyEntry>,
+}
+
+impl ApiKeyConfig {
+    pub fn new(keys: Vec<ApiKeyEntry>) -> Self {
+        Self { keys }
+    }
+
+    fn authorize(&self, presented: Option<&str>, now: f64) -> Result<(), AuthRejection> {
+        if self.keys.is_empty() {
+            return Ok(());
+        }
+        let header = presented.ok_or(AuthRejection::Missing)?;
+        let token = header.strip_prefix("Bearer ").unwrap_or(header);
+        match self.keys.iter().find(|entry| entry.key == token) {
+            Some(entry) if entry.is_valid_at(now) => Ok(()),
+            Some(_) => Err(AuthRejection::Expired),
+            None => Err(AuthRejection::Unknown),
+        }
+    }
+}
+
+/// Rejection raised when a request fails the API-key check.
+#[derive(Debug)]
+pub enum AuthRejection {
+    Missing,
+    Unknown,
+    Expired,
+}
+
+impl warp::reject::Reject for AuthRejection {}
+
+fn current_unix_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or(0.0)
+}
+
+/// Builds a filter that rejects requests missing a valid `authorization`
+/// header, for routes to `.and()` against.
+pub fn api_key_filter(
+    config: ApiKeyConfig,
+) -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    warp::header::optional::<String>("authorization")
+        .and_then(move |header: Option<String>| {
+            let config = config.clone();
+            async move {
+                config
+                    .authorize(header.as_deref(), current_unix_time())
+                    .map_err(warp::reject::custom)
+            }
+        })
+        .untuple_one()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_key_set_allows_any_request() {
+        let config = ApiKeyConfig::default();
+        assert!(config.authorize(None, 100.0).is_ok());
+    }
+
+    #[test]
+    fn missing_header_is_rejected_when_keys_configured() {
+        let config = ApiKeyConfig::new(vec![ApiKeyEntry {
+            key: "secret".into(),
+            not_before: None,
+            not_after: None,
+        }]);
+        assert!(matches!(
+            config.authorize(None, 100.0),
+            Err(AuthRejection::Missing)
+        ));
+    }
+
+    #[test]
+    fn unknown_key_is_rejected() {
+        let config = ApiKeyConfig::new(vec![ApiKeyEntry {
+            key: "secret".into(),
+            not_before: None,
+            not_after: None,
+        }]);
+        assert!(matches!(
+            config.authorize(Some("Bearer wrong"), 100.0),
+            Err(AuthRejection::Unknown)
+        ));
+    }
+
+    #[test]
+    fn expired_key_is_rejected() {
+        let config = ApiKeyConfig::new(vec![ApiKeyEntry {
+            key: "secret".into(),
+            not_before: None,
+            not_after: Some(50.0),
+        }]);
+        assert!(matches!(
+            config.authorize(Some("Bearer secret"), 100.0),
+            Err(AuthRejection::Expired)
+        ));
+    }
+
+    #[test]
+    fn valid_key_within_window_is_accepted() {
+        let config = ApiKeyConfig::new(vec![ApiKeyEntry {
+            key: "secret".into(),
+            not_before: Some(10.0),
+            not_after: Some(200.0),
+        }]);
+        assert!(config.authorize(Some("Bearer secret"), 100.0).is_ok());
+    }
+}
@@ -1,137 +1,453 @@
 use crate::generator::profile::{build_pri_payload_from_config, GeneratorConfig};
+use crate::gui_bridge::auth::{api_key_filter, ApiKeyConfig, AuthRejection};
+use crate::gui_bridge::config::GuiBridgeConfig;
+use crate::gui_bridge::history::HistoryStore;
+use crate::gui_bridge::metrics::PipelineMetrics;
 use crate::gui_bridge::model::VisualizationModel;
 use crate::workflow::runner::Runner;
+use crate::workflow::settings::{command_channel, SettingsCommand};
 use anyhow::Result;
+use dashmap::DashMap;
+use futures_util::{SinkExt, StreamExt};
 use gmticore::agp_interface::PriPayload;
+use serde::Deserialize;
 use serde_json::json;
-use std::{
-    net::SocketAddr,
-    sync::{Arc, RwLock},
-    thread,
-};
+use std::net::SocketAddr;
+use std::sync::mpsc::Sender;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use std::{convert::Infallible, sync::Arc, thread};
 use tokio::runtime::Builder;
-use warp::{http::StatusCode, Filter};
+use tokio::sync::{broadcast, oneshot};
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
+use warp::{http::StatusCode, sse::Event, Filter};
 
-fn gui_bind_address() -> SocketAddr {
-    SocketAddr::from(([127, 0, 0, 1], 9000))
-}
+/// Capacity of the broadcast channel backing `/events`; slow subscribers lag
+/// rather than stall the producers once this many updates have queued up.
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+
+/// Session id used by the unparameterized `/payload` and `/ingest` routes so
+/// single-scenario callers keep working without naming a session.
+const DEFAULT_SESSION: &str = "default";
 
 #[derive(Debug)]
 struct WarpError;
 
 impl warp::reject::Reject for WarpError {}
 
+type SessionMap = Arc<DashMap<String, VisualizationModel>>;
+
+/// Query parameters accepted by `GET /history`.
+#[derive(Debug, Deserialize)]
+struct HistoryQuery {
+    #[serde(default = "default_history_limit")]
+    limit: usize,
+    scenario: Option<String>,
+}
+
+fn default_history_limit() -> usize {
+    20
+}
+
+fn current_unix_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or(0.0)
+}
+
 /// Bridge that hosts the telemetry HTTP endpoint and processes incoming payloads.
+///
+/// State is keyed by session id so several scenarios can be driven
+/// concurrently from one process without clobbering each other's results.
+/// The server thread is joined on `shutdown()` (or when the bridge is
+/// dropped) rather than left detached for the process lifetime.
 pub struct GuiBridge {
-    state: Arc<RwLock<VisualizationModel>>,
+    sessions: SessionMap,
+    events: broadcast::Sender<VisualizationModel>,
+    history: Arc<HistoryStore>,
+    metrics: Arc<PipelineMetrics>,
+    local_addr: SocketAddr,
+    shutdown: Option<oneshot::Sender<()>>,
+    server_thread: Option<thread::JoinHandle<()>>,
 }
 
 impl GuiBridge {
     pub fn new(runner: Arc<Runner>) -> Self {
-        let state = Arc::new(RwLock::new(VisualizationModel::default()));
-        let state_for_filter = state.clone();
-        let state_filter = warp::any().map(move || state_for_filter.clone());
+        Self::with_config(runner, ApiKeyConfig::default(), GuiBridgeConfig::default())
+    }
+
+    /// Builds the bridge with an API-key policy guarding its mutating routes.
+    /// An empty `auth.keys` leaves those routes open, matching `new`.
+    pub fn with_auth(runner: Arc<Runner>, auth: ApiKeyConfig) -> Self {
+        Self::with_config(runner, auth, GuiBridgeConfig::default())
+    }
+
+    /// Builds the bridge with an explicit bind address/TLS policy in addition
+    /// to the API-key policy, rather than the hard-coded loopback address.
+    pub fn with_config(runner: Arc<Runner>, auth: ApiKeyConfig, config: GuiBridgeConfig) -> Self {
+        let sessions: SessionMap = Arc::new(DashMap::new());
+        sessions.insert(DEFAULT_SESSION.to_string(), VisualizationModel::default());
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let history = Arc::new(match config.history_db_path.as_ref() {
+            Some(path) => HistoryStore::open(&path.to_string_lossy())
+                .expect("failed to open history database"),
+            None => HistoryStore::in_memory().expect("failed to open in-memory history store"),
+        });
+        let metrics = Arc::new(PipelineMetrics::new());
+
+        let (settings_tx, settings_rx) = command_channel();
+        runner.settings().spawn_command_loop(settings_rx);
+        let settings_tx_filter = warp::any().map(move || settings_tx.clone());
+
+        let sessions_for_filter = sessions.clone();
+        let sessions_filter = warp::any().map(move || sessions_for_filter.clone());
         let runner_filter = warp::any().map(move || runner.clone());
+        let events_for_filter = events.clone();
+        let events_filter = warp::any().map(move || events_for_filter.clone());
+        let history_for_filter = history.clone();
+        let history_filter = warp::any().map(move || history_for_filter.clone());
+        let metrics_for_filter = metrics.clone();
+        let metrics_filter = warp::any().map(move || metrics_for_filter.clone());
+        let auth_filter = api_key_filter(auth);
 
         let get_route = warp::path("payload")
+            .and(warp::path::end())
             .and(warp::get())
-            .and(state_filter.clone())
-            .map(|state: Arc<RwLock<VisualizationModel>>| {
-                warp::reply::json(&*state.read().unwrap())
+            .and(sessions_filter.clone())
+            .map(|sessions: SessionMap| {
+                let model = sessions
+                    .get(DEFAULT_SESSION)
+                    .map(|entry| entry.clone())
+                    .unwrap_or_default();
+                warp::reply::json(&model)
+            });
+
+        let get_session_route = warp::path!("payload" / String)
+            .and(warp::get())
+            .and(sessions_filter.clone())
+            .map(|session: String, sessions: SessionMap| match sessions.get(&session) {
+                Some(entry) => warp::reply::with_status(warp::reply::json(&*entry), StatusCode::OK),
+                None => warp::reply::with_status(
+                    warp::reply::json(&json!({"status": "error", "reason": "unknown session"})),
+                    StatusCode::NOT_FOUND,
+                ),
+            });
+
+        let events_route = warp::path("events")
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(events_filter.clone())
+            .map(|events: broadcast::Sender<VisualizationModel>| {
+                let stream = BroadcastStream::new(events.subscribe()).filter_map(|item| async {
+                    match item {
+                        Ok(model) => Some(Ok::<_, Infallible>(
+                            Event::default().json_data(&model).unwrap_or_else(|_| {
+                                Event::default().data("serialization error")
+                            }),
+                        )),
+                        Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                            eprintln!("[GUI] /events subscriber lagged, skipped {} updates", skipped);
+                            None
+                        }
+                    }
+                });
+                warp::sse::reply(warp::sse::keep_alive().stream(stream))
+            });
+
+        let stream_route = warp::path("stream")
+            .and(warp::path::end())
+            .and(warp::ws())
+            .and(events_filter.clone())
+            .map(|ws: warp::ws::Ws, events: broadcast::Sender<VisualizationModel>| {
+                ws.on_upgrade(move |socket| async move {
+                    let (mut sink, _) = socket.split();
+                    let mut updates = BroadcastStream::new(events.subscribe());
+                    while let Some(item) = updates.next().await {
+                        let model = match item {
+                            Ok(model) => model,
+                            Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                                eprintln!("[GUI] /stream subscriber lagged, skipped {} updates", skipped);
+                                continue;
+                            }
+                        };
+                        let payload = match serde_json::to_string(&model) {
+                            Ok(payload) => payload,
+                            Err(_) => continue,
+                        };
+                        if sink.send(warp::ws::Message::text(payload)).await.is_err() {
+                            break;
+                        }
+                    }
+                })
             });
 
         let post_route = warp::path("ingest")
+            .and(warp::path::end())
             .and(warp::post())
+            .and(auth_filter.clone())
             .and(warp::body::json())
-            .and(state_filter.clone())
+            .and(sessions_filter.clone())
             .and(runner_filter.clone())
+            .and(events_filter.clone())
+            .and(history_filter.clone())
+            .and(metrics_filter.clone())
             .and_then(
                 |payload: PriPayload,
-                 state: Arc<RwLock<VisualizationModel>>,
-                 runner: Arc<Runner>| async move {
-                    match runner.execute(&payload) {
-                        Ok(result) => {
-                            let mut guard = state.write().unwrap();
-                            *guard = VisualizationModel {
-                                power_profile: result.power_profile.clone(),
-                                detection_count: result.detection_count,
-                            };
-                            Ok::<_, warp::Rejection>(warp::reply::with_status(
-                                warp::reply::json(&json!({"status": "ok"})),
-                                StatusCode::OK,
-                            ))
-                        }
-                        Err(err) => {
-                            eprintln!("ingest error: {}", err);
-                            Err(warp::reject::custom(WarpError))
-                        }
-                    }
+                 sessions: SessionMap,
+                 runner: Arc<Runner>,
+                 events: broadcast::Sender<VisualizationModel>,
+                 history: Arc<HistoryStore>,
+                 metrics: Arc<PipelineMetrics>| async move {
+                    ingest(
+                        payload,
+                        DEFAULT_SESSION.to_string(),
+                        &sessions,
+                        &runner,
+                        &events,
+                        &history,
+                        &metrics,
+                        None,
+                    )
+                },
+            );
+
+        let post_session_route = warp::path!("ingest" / String)
+            .and(warp::post())
+            .and(auth_filter.clone())
+            .and(warp::body::json())
+            .and(sessions_filter.clone())
+            .and(runner_filter.clone())
+            .and(events_filter.clone())
+            .and(history_filter.clone())
+            .and(metrics_filter.clone())
+            .and_then(
+                |session: String,
+                 payload: PriPayload,
+                 sessions: SessionMap,
+                 runner: Arc<Runner>,
+                 events: broadcast::Sender<VisualizationModel>,
+                 history: Arc<HistoryStore>,
+                 metrics: Arc<PipelineMetrics>| async move {
+                    let scenario = Some(session.clone());
+                    ingest(
+                        payload, session, &sessions, &runner, &events, &history, &metrics, scenario,
+                    )
                 },
             );
 
         let generator_route = warp::path("ingest-config")
+            .and(warp::path::end())
             .and(warp::post())
+            .and(auth_filter.clone())
             .and(warp::body::json())
-            .and(state_filter)
-            .and(runner_filter)
+            .and(sessions_filter.clone())
+            .and(runner_filter.clone())
+            .and(events_filter.clone())
+            .and(history_filter.clone())
+            .and(metrics_filter.clone())
             .and_then(
                 |config: GeneratorConfig,
-                 state: Arc<RwLock<VisualizationModel>>,
-                 runner: Arc<Runner>| async move {
-                    match build_pri_payload_from_config(&config)
-                        .and_then(|payload| runner.execute(&payload))
-                    {
-                        Ok(result) => {
-                            let mut guard = state.write().unwrap();
-                            *guard = VisualizationModel {
-                                power_profile: result.power_profile.clone(),
-                                detection_count: result.detection_count,
-                            };
-                            if let Some(name) = config.scenario.as_ref() {
-                                println!(
-                                    "[GUI] Scenario {} -> detections {}",
-                                    name, result.detection_count
-                                );
-                            }
-                            Ok::<_, warp::Rejection>(warp::reply::with_status(
-                                warp::reply::json(&json!({
-                                    "status": "ok",
-                                    "detections": result.detection_count,
-                                    "description": config.description.clone().unwrap_or_default()
-                                })),
-                                StatusCode::OK,
-                            ))
-                        }
-                        Err(err) => {
-                            eprintln!("ingest-config error: {}", err);
-                            Err(warp::reject::custom(WarpError))
-                        }
-                    }
+                 sessions: SessionMap,
+                 runner: Arc<Runner>,
+                 events: broadcast::Sender<VisualizationModel>,
+                 history: Arc<HistoryStore>,
+                 metrics: Arc<PipelineMetrics>| async move {
+                    ingest_config(
+                        config,
+                        DEFAULT_SESSION.to_string(),
+                        &sessions,
+                        &runner,
+                        &events,
+                        &history,
+                        &metrics,
+                    )
                 },
             );
 
-        thread::spawn(move || {
-            let routes = get_route.or(post_route).or(generator_route);
+        let generator_session_route = warp::path!("ingest-config" / String)
+            .and(warp::post())
+            .and(auth_filter.clone())
+            .and(warp::body::json())
+            .and(sessions_filter.clone())
+            .and(runner_filter.clone())
+            .and(events_filter.clone())
+            .and(history_filter.clone())
+            .and(metrics_filter.clone())
+            .and_then(
+                |session: String,
+                 config: GeneratorConfig,
+                 sessions: SessionMap,
+                 runner: Arc<Runner>,
+                 events: broadcast::Sender<VisualizationModel>,
+                 history: Arc<HistoryStore>,
+                 metrics: Arc<PipelineMetrics>| async move {
+                    ingest_config(
+                        config, session, &sessions, &runner, &events, &history, &metrics,
+                    )
+                },
+            );
+
+        let settings_route = warp::path("settings")
+            .and(warp::path::end())
+            .and(warp::post())
+            .and(auth_filter.clone())
+            .and(warp::body::json())
+            .and(settings_tx_filter)
+            .map(|command: SettingsCommand, settings_tx: Sender<SettingsCommand>| {
+                match settings_tx.send(command) {
+                    Ok(()) => warp::reply::with_status(
+                        warp::reply::json(&json!({"status": "ok"})),
+                        StatusCode::ACCEPTED,
+                    ),
+                    Err(err) => {
+                        eprintln!("settings command channel closed: {}", err);
+                        warp::reply::with_status(
+                            warp::reply::json(&json!({"status": "error"})),
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                        )
+                    }
+                }
+            });
+
+        let history_route = warp::path("history")
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(warp::query::<HistoryQuery>())
+            .and(history_filter.clone())
+            .map(|query: HistoryQuery, history: Arc<HistoryStore>| {
+                match history.recent(query.limit, query.scenario.as_deref()) {
+                    Ok(entries) => {
+                        warp::reply::with_status(warp::reply::json(&entries), StatusCode::OK)
+                    }
+                    Err(err) => {
+                        eprintln!("history query error: {}", err);
+                        warp::reply::with_status(
+                            warp::reply::json(&json!({"status": "error"})),
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                        )
+                    }
+                }
+            });
+
+        let history_entry_route = warp::path!("history" / i64)
+            .and(warp::get())
+            .and(history_filter)
+            .map(|id: i64, history: Arc<HistoryStore>| match history.get(id) {
+                Ok(Some(entry)) => {
+                    warp::reply::with_status(warp::reply::json(&entry), StatusCode::OK)
+                }
+                Ok(None) => warp::reply::with_status(
+                    warp::reply::json(&json!({"status": "error", "reason": "unknown run"})),
+                    StatusCode::NOT_FOUND,
+                ),
+                Err(err) => {
+                    eprintln!("history lookup error: {}", err);
+                    warp::reply::with_status(
+                        warp::reply::json(&json!({"status": "error"})),
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                    )
+                }
+            });
+
+        let metrics_route = warp::path("metrics")
+            .and(warp::path::end())
+            .and(warp::get())
+            .and(metrics_filter)
+            .map(|metrics: Arc<PipelineMetrics>| match metrics.encode() {
+                Ok(body) => warp::reply::with_status(body, StatusCode::OK),
+                Err(err) => {
+                    eprintln!("metrics encode error: {}", err);
+                    warp::reply::with_status(String::new(), StatusCode::INTERNAL_SERVER_ERROR)
+                }
+            });
+
+        let bind_addr = config.bind_addr;
+        let tls = config.tls;
+        let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+        let (addr_tx, addr_rx) = std::sync::mpsc::channel::<SocketAddr>();
+        let server_thread = thread::spawn(move || {
+            let routes = get_route
+                .or(get_session_route)
+                .or(events_route)
+                .or(stream_route)
+                .or(post_route)
+                .or(post_session_route)
+                .or(generator_route)
+                .or(generator_session_route)
+                .or(settings_route)
+                .or(history_route)
+                .or(history_entry_route)
+                .or(metrics_route)
+                .recover(handle_rejection);
             let runtime = Builder::new_current_thread()
                 .enable_all()
                 .build()
                 .expect("failed to build runtime");
             runtime.block_on(async move {
-                warp::serve(routes).run(gui_bind_address()).await;
+                match tls {
+                    Some(tls) => {
+                        let (addr, server) = warp::serve(routes)
+                            .tls()
+                            .cert_path(tls.cert_path)
+                            .key_path(tls.key_path)
+                            .bind_with_graceful_shutdown(bind_addr, async {
+                                shutdown_rx.await.ok();
+                            });
+                        let _ = addr_tx.send(addr);
+                        server.await;
+                    }
+                    None => {
+                        let (addr, server) = warp::serve(routes)
+                            .bind_with_graceful_shutdown(bind_addr, async {
+                                shutdown_rx.await.ok();
+                            });
+                        let _ = addr_tx.send(addr);
+                        server.await;
+                    }
+                }
             });
         });
+        let local_addr = addr_rx
+            .recv()
+            .expect("server thread exited before reporting its bound address");
+
+        Self {
+            sessions,
+            events,
+            history,
+            metrics,
+            local_addr,
+            shutdown: Some(shutdown_tx),
+            server_thread: Some(server_thread),
+        }
+    }
+
+    /// The address the server actually bound to; differs from
+    /// `GuiBridgeConfig::bind_addr` when that port was `0`.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
 
-        Self { state }
+    /// Signals the server's graceful-shutdown future and joins its thread.
+    /// Safe to call more than once; subsequent calls are a no-op.
+    pub fn shutdown(&mut self) {
+        if let Some(tx) = self.shutdown.take() {
+            let _ = tx.send(());
+        }
+        if let Some(handle) = self.server_thread.take() {
+            let _ = handle.join();
+        }
     }
 
     pub fn publish(&self, model: &VisualizationModel) -> Result<()> {
-        let mut guard = self.state.write().unwrap();
-        *guard = model.clone();
+        self.sessions
+            .insert(DEFAULT_SESSION.to_string(), model.clone());
         println!(
             "[GUI] power profile points: {}, detections: {}",
-            guard.power_profile.len(),
-            guard.detection_count
+            model.power_profile.len(),
+            model.detection_count
         );
+        let _ = self.events.send(model.clone());
         Ok(())
     }
 
@@ -141,10 +457,165 @@ impl GuiBridge {
 
     #[cfg(test)]
     pub fn snapshot(&self) -> VisualizationModel {
-        self.state.read().unwrap().clone()
+        self.sessions
+            .get(DEFAULT_SESSION)
+            .map(|entry| entry.clone())
+            .unwrap_or_default()
+    }
+
+    #[cfg(test)]
+    pub fn snapshot_session(&self, session: &str) -> Option<VisualizationModel> {
+        self.sessions.get(session).map(|entry| entry.clone())
+    }
+
+    #[cfg(test)]
+    pub fn history(&self) -> &HistoryStore {
+        &self.history
+    }
+
+    #[cfg(test)]
+    pub fn metrics(&self) -> &PipelineMetrics {
+        &self.metrics
+    }
+}
+
+impl Drop for GuiBridge {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+fn ingest(
+    payload: PriPayload,
+    session: String,
+    sessions: &SessionMap,
+    runner: &Runner,
+    events: &broadcast::Sender<VisualizationModel>,
+    history: &HistoryStore,
+    metrics: &PipelineMetrics,
+    scenario: Option<String>,
+) -> Result<warp::reply::WithStatus<warp::reply::Json>, warp::Rejection> {
+    metrics.record_request();
+    let started = Instant::now();
+    match runner.execute(&payload) {
+        Ok(result) => {
+            metrics.record_execute(started.elapsed().as_secs_f64(), &result.stage_timings);
+            metrics.set_last_detection_count(result.detection_count);
+            let model = VisualizationModel {
+                power_profile: result.power_profile.clone(),
+                detection_count: result.detection_count,
+                detection_records: result.detection_records.clone(),
+                detection_notes: result.doppler_notes.clone(),
+                scenario_metadata: result.scenario_metadata.clone(),
+                range_doppler_matrix: result.range_doppler_matrix.clone(),
+                range_doppler_range_bins: result.range_bins,
+                range_doppler_doppler_bins: result.doppler_bins,
+            };
+            if let Err(err) = history.record(
+                current_unix_time(),
+                scenario.as_deref(),
+                None,
+                result.detection_count,
+                &result.power_profile,
+            ) {
+                eprintln!("history record error: {}", err);
+            }
+            sessions.insert(session, model.clone());
+            let _ = events.send(model);
+            Ok(warp::reply::with_status(
+                warp::reply::json(&json!({"status": "ok"})),
+                StatusCode::OK,
+            ))
+        }
+        Err(err) => {
+            metrics.record_error();
+            eprintln!("ingest error: {}", err);
+            Err(warp::reject::custom(WarpError))
+        }
     }
 }
 
+fn ingest_config(
+    config: GeneratorConfig,
+    session: String,
+    sessions: &SessionMap,
+    runner: &Runner,
+    events: &broadcast::Sender<VisualizationModel>,
+    history: &HistoryStore,
+    metrics: &PipelineMetrics,
+) -> Result<warp::reply::WithStatus<warp::reply::Json>, warp::Rejection> {
+    metrics.record_request();
+    let started = Instant::now();
+    match build_pri_payload_from_config(&config).and_then(|payload| runner.execute(&payload)) {
+        Ok(result) => {
+            metrics.record_execute(started.elapsed().as_secs_f64(), &result.stage_timings);
+            metrics.set_last_detection_count(result.detection_count);
+            let model = VisualizationModel {
+                power_profile: result.power_profile.clone(),
+                detection_count: result.detection_count,
+                detection_records: result.detection_records.clone(),
+                detection_notes: result.doppler_notes.clone(),
+                scenario_metadata: result.scenario_metadata.clone(),
+                range_doppler_matrix: result.range_doppler_matrix.clone(),
+                range_doppler_range_bins: result.range_bins,
+                range_doppler_doppler_bins: result.doppler_bins,
+            };
+            sessions.insert(session, model.clone());
+            let _ = events.send(model);
+            if let Some(name) = config.scenario.as_ref() {
+                println!("[GUI] Scenario {} -> detections {}", name, result.detection_count);
+            }
+            if let Err(err) = history.record(
+                current_unix_time(),
+                config.scenario.as_deref(),
+                config.description.as_deref(),
+                result.detection_count,
+                &result.power_profile,
+            ) {
+                eprintln!("history record error: {}", err);
+            }
+            Ok(warp::reply::with_status(
+                warp::reply::json(&json!({
+                    "status": "ok",
+                    "detections": result.detection_count,
+                    "description": config.description.clone().unwrap_or_default()
+                })),
+                StatusCode::OK,
+            ))
+        }
+        Err(err) => {
+            metrics.record_error();
+            eprintln!("ingest-config error: {}", err);
+            Err(warp::reject::custom(WarpError))
+        }
+    }
+}
+
+/// Maps rejections into the HTTP responses the telemetry endpoints promise:
+/// missing/unknown keys are `401`, an expired key is `403`.
+async fn handle_rejection(err: warp::Rejection) -> Result<impl warp::Reply, Infallible> {
+    if let Some(auth_err) = err.find::<AuthRejection>() {
+        let status = match auth_err {
+            AuthRejection::Expired => StatusCode::FORBIDDEN,
+            AuthRejection::Missing | AuthRejection::Unknown => StatusCode::UNAUTHORIZED,
+        };
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&json!({"status": "error", "reason": format!("{:?}", auth_err)})),
+            status,
+        ));
+    }
+    if err.find::<WarpError>().is_some() {
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&json!({"status": "error"})),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ));
+    }
+    Ok(warp::reply::with_status(
+        warp::reply::json(&json!({"status": "error", "reason": "not found"})),
+        StatusCode::NOT_FOUND,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,18 +624,140 @@ mod tests {
     use crate::workflow::runner::Runner;
     use std::sync::Arc;
 
+    /// Builds a bridge bound to an ephemeral port so parallel test runs don't
+    /// fight over `GuiBridgeConfig::default()`'s fixed port 9000.
+    fn test_bridge(runner: Arc<Runner>) -> GuiBridge {
+        GuiBridge::with_config(
+            runner,
+            ApiKeyConfig::default(),
+            GuiBridgeConfig {
+                bind_addr: SocketAddr::from(([127, 0, 0, 1], 0)),
+                ..GuiBridgeConfig::default()
+            },
+        )
+    }
+
     #[test]
     fn gui_bridge_updates_state() {
         let cfg = WorkflowConfig::from_args(1, 8, 4);
         let runner = Arc::new(Runner::new(cfg.clone()));
-        let gui = GuiBridge::new(runner.clone());
+        let gui = test_bridge(runner.clone());
         let payload = build_pri_payload(cfg.taps, cfg.range_bins).unwrap();
         let result = runner.execute(&payload).unwrap();
         let model = VisualizationModel {
             power_profile: result.power_profile.clone(),
             detection_count: result.detection_count,
+            detection_records: result.detection_records.clone(),
+            detection_notes: result.doppler_notes.clone(),
+            scenario_metadata: result.scenario_metadata.clone(),
+            range_doppler_matrix: result.range_doppler_matrix.clone(),
+            range_doppler_range_bins: result.range_bins,
+            range_doppler_doppler_bins: result.doppler_bins,
         };
         gui.publish(&model).unwrap();
         assert_eq!(gui.snapshot().detection_count, result.detection_count);
     }
+
+    #[test]
+    fn gui_bridge_broadcasts_events_on_publish() {
+        let cfg = WorkflowConfig::from_args(1, 8, 4);
+        let runner = Arc::new(Runner::new(cfg.clone()));
+        let gui = test_bridge(runner.clone());
+        let mut receiver = gui.events.subscribe();
+        let payload = build_pri_payload(cfg.taps, cfg.range_bins).unwrap();
+        let result = runner.execute(&payload).unwrap();
+        let model = VisualizationModel {
+            power_profile: result.power_profile.clone(),
+            detection_count: result.detection_count,
+            detection_records: result.detection_records.clone(),
+            detection_notes: result.doppler_notes.clone(),
+            scenario_metadata: result.scenario_metadata.clone(),
+            range_doppler_matrix: result.range_doppler_matrix.clone(),
+            range_doppler_range_bins: result.range_bins,
+            range_doppler_doppler_bins: result.doppler_bins,
+        };
+        gui.publish(&model).unwrap();
+        let received = receiver.try_recv().expect("expected a broadcast event");
+        assert_eq!(received.detection_count, model.detection_count);
+    }
+
+    #[test]
+    fn gui_bridge_keeps_sessions_isolated() {
+        let cfg = WorkflowConfig::from_args(1, 8, 4);
+        let runner = Arc::new(Runner::new(cfg.clone()));
+        let gui = test_bridge(runner);
+        assert!(gui.snapshot_session("scenario-a").is_none());
+
+        let model = VisualizationModel {
+            detection_count: 3,
+            ..VisualizationModel::default()
+        };
+        gui.sessions.insert("scenario-a".to_string(), model.clone());
+        assert_eq!(
+            gui.snapshot_session("scenario-a").unwrap().detection_count,
+            3
+        );
+        assert_ne!(gui.snapshot().detection_count, 3);
+    }
+
+    #[test]
+    fn ingest_records_a_history_entry() {
+        let cfg = WorkflowConfig::from_args(1, 8, 4);
+        let runner = Arc::new(Runner::new(cfg.clone()));
+        let gui = test_bridge(runner.clone());
+        let sessions: SessionMap = Arc::new(DashMap::new());
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let payload = build_pri_payload(cfg.taps, cfg.range_bins).unwrap();
+
+        ingest(
+            payload,
+            DEFAULT_SESSION.to_string(),
+            &sessions,
+            &runner,
+            &events,
+            gui.history(),
+            gui.metrics(),
+            Some("scenario-a".to_string()),
+        )
+        .unwrap();
+
+        let recorded = gui.history().recent(10, Some("scenario-a")).unwrap();
+        assert_eq!(recorded.len(), 1);
+    }
+
+    #[test]
+    fn ingest_records_pipeline_metrics() {
+        let cfg = WorkflowConfig::from_args(1, 8, 4);
+        let runner = Arc::new(Runner::new(cfg.clone()));
+        let gui = test_bridge(runner.clone());
+        let sessions: SessionMap = Arc::new(DashMap::new());
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let payload = build_pri_payload(cfg.taps, cfg.range_bins).unwrap();
+
+        ingest(
+            payload,
+            DEFAULT_SESSION.to_string(),
+            &sessions,
+            &runner,
+            &events,
+            gui.history(),
+            gui.metrics(),
+            None,
+        )
+        .unwrap();
+
+        let rendered = gui.metrics().encode().unwrap();
+        assert!(rendered.contains("gmti_ingest_requests_total 1"));
+    }
+
+    #[test]
+    fn shutdown_reports_bound_port_and_joins_cleanly() {
+        let cfg = WorkflowConfig::from_args(1, 8, 4);
+        let runner = Arc::new(Runner::new(cfg));
+        let mut gui = test_bridge(runner);
+        assert_ne!(gui.local_addr().port(), 0);
+
+        gui.shutdown();
+        gui.shutdown();
+    }
 }
@@ -0,0 +1,30 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+/// Filesystem paths to a PEM certificate and private key for TLS.
+#[derive(Debug, Clone)]
+pub struct TlsPaths {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+}
+
+/// Bind address, optional TLS material, and history persistence for the GUI
+/// bridge server.
+#[derive(Debug, Clone)]
+pub struct GuiBridgeConfig {
+    pub bind_addr: SocketAddr,
+    pub tls: Option<TlsPaths>,
+    /// Path to the SQLite history database. `None` keeps history in memory,
+    /// so it is lost when the process exits.
+    pub history_db_path: Option<PathBuf>,
+}
+
+impl Default for GuiBridgeConfig {
+    fn default() -> Self {
+        Self {
+            bind_addr: SocketAddr::from(([127, 0, 0, 1], 9000)),
+            tls: None,
+            history_db_path: None,
+        }
+    }
+}
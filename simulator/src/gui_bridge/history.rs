@@ -0,0 +1,173 @@
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection, Row};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+/// A single recorded `runner.execute` result, as returned by `HistoryStore`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub id: i64,
+    pub timestamp: f64,
+    pub scenario: Option<String>,
+    pub description: Option<String>,
+    pub detection_count: usize,
+    pub power_profile: Vec<f32>,
+}
+
+/// Lightweight SQLite-backed log of every ingested run, queryable by recency
+/// or scenario so operators can compare detection counts across sweeps.
+pub struct HistoryStore {
+    conn: Mutex<Connection>,
+}
+
+impl HistoryStore {
+    pub fn open(path: &str) -> Result<Self> {
+        let conn =
+            Connection::open(path).with_context(|| format!("opening history store {}", path))?;
+        Self::from_connection(conn)
+    }
+
+    pub fn in_memory() -> Result<Self> {
+        let conn = Connection::open_in_memory().context("opening in-memory history store")?;
+        Self::from_connection(conn)
+    }
+
+    fn from_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp REAL NOT NULL,
+                scenario TEXT,
+                description TEXT,
+                detection_count INTEGER NOT NULL,
+                power_profile TEXT NOT NULL
+            );",
+        )
+        .context("creating history schema")?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Inserts a row for a completed run, returning its assigned id.
+    pub fn record(
+        &self,
+        timestamp: f64,
+        scenario: Option<&str>,
+        description: Option<&str>,
+        detection_count: usize,
+        power_profile: &[f32],
+    ) -> Result<i64> {
+        let conn = self.conn.lock().unwrap();
+        let serialized =
+            serde_json::to_string(power_profile).context("serializing power profile")?;
+        conn.execute(
+            "INSERT INTO runs (timestamp, scenario, description, detection_count, power_profile)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                timestamp,
+                scenario,
+                description,
+                detection_count as i64,
+                serialized
+            ],
+        )
+        .context("inserting history row")?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Returns the most recent `limit` runs, optionally filtered by scenario.
+    pub fn recent(&self, limit: usize, scenario: Option<&str>) -> Result<Vec<HistoryEntry>> {
+        let conn = self.conn.lock().unwrap();
+        let limit = limit.max(1) as i64;
+        let entries = match scenario {
+            Some(scenario) => {
+                let mut stmt = conn.prepare(
+                    "SELECT id, timestamp, scenario, description, detection_count, power_profile
+                     FROM runs WHERE scenario = ?1 ORDER BY id DESC LIMIT ?2",
+                )?;
+                stmt.query_map(params![scenario, limit], Self::map_row)?
+                    .collect::<rusqlite::Result<Vec<_>>>()
+            }
+            None => {
+                let mut stmt = conn.prepare(
+                    "SELECT id, timestamp, scenario, description, detection_count, power_profile
+                     FROM runs ORDER BY id DESC LIMIT ?1",
+                )?;
+                stmt.query_map(params![limit], Self::map_row)?
+                    .collect::<rusqlite::Result<Vec<_>>>()
+            }
+        };
+        entries.context("reading history rows")
+    }
+
+    /// Fetches a single run by id, if it exists.
+    pub fn get(&self, id: i64) -> Result<Option<HistoryEntry>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, timestamp, scenario, description, detection_count, power_profile
+             FROM runs WHERE id = ?1",
+        )?;
+        let mut rows = stmt.query_map(params![id], Self::map_row)?;
+        match rows.next() {
+            Some(row) => Ok(Some(row?)),
+            None => Ok(None),
+        }
+    }
+
+    fn map_row(row: &Row) -> rusqlite::Result<HistoryEntry> {
+        let power_profile_json: String = row.get(5)?;
+        let power_profile: Vec<f32> = serde_json::from_str(&power_profile_json).unwrap_or_default();
+        Ok(HistoryEntry {
+            id: row.get(0)?,
+            timestamp: row.get(1)?,
+            scenario: row.get(2)?,
+            description: row.get(3)?,
+            detection_count: row.get::<_, i64>(4)? as usize,
+            power_profile,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_and_recalls_a_run() {
+        let store = HistoryStore::in_memory().unwrap();
+        let id = store
+            .record(12.5, Some("airborne-sweep"), Some("test run"), 7, &[1.0, 2.0])
+            .unwrap();
+
+        let fetched = store.get(id).unwrap().expect("row should exist");
+        assert_eq!(fetched.detection_count, 7);
+        assert_eq!(fetched.power_profile, vec![1.0, 2.0]);
+        assert_eq!(fetched.scenario.as_deref(), Some("airborne-sweep"));
+    }
+
+    #[test]
+    fn recent_filters_by_scenario_and_limit() {
+        let store = HistoryStore::in_memory().unwrap();
+        store.record(1.0, Some("a"), None, 1, &[]).unwrap();
+        store.record(2.0, Some("b"), None, 2, &[]).unwrap();
+        store.record(3.0, Some("a"), None, 3, &[]).unwrap();
+
+        let all = store.recent(10, None).unwrap();
+        assert_eq!(all.len(), 3);
+
+        let scoped = store.recent(10, Some("a")).unwrap();
+        assert_eq!(scoped.len(), 2);
+        assert!(scoped.iter().all(|entry| entry.scenario.as_deref() == Some("a")));
+
+        let limited = store.recent(1, None).unwrap();
+        assert_eq!(limited.len(), 1);
+        assert_eq!(limited[0].detection_count, 3);
+    }
+
+    #[test]
+    fn get_returns_none_for_unknown_id() {
+        let store = HistoryStore::in_memory().unwrap();
+        assert!(store.get(999).unwrap().is_none());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::workflow::runner::StageTimings;
+use anyhow::{Context, Result};
+use prometheus::{Encoder, Histogram, HistogramOpts, IntCounter, IntGauge, Registry, TextEncoder};
+
+/// Prometheus counters/histograms for the `/ingest` and `/ingest-config`
+/// paths, exposed to operators via `GET /metrics`.
+pub struct PipelineMetrics {
+    registry: Registry,
+    requests_total: IntCounter,
+    errors_total: IntCounter,
+    execute_latency: Histogram,
+    range_stage_latency: Histogram,
+    doppler_stage_latency: Histogram,
+    clutter_stage_latency: Histogram,
+    last_detection_count: IntGauge,
+}
+
+impl PipelineMetrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let requests_total = IntCounter::new(
+            "gmti_ingest_requests_total",
+            "Total number of /ingest and /ingest-config requests received",
+        )
+        .expect("valid counter opts");
+        let errors_total = IntCounter::new(
+            "gmti_ingest_errors_total",
+            "Total number of /ingest and /ingest-config requests that failed",
+        )
+        .expect("valid counter opts");
+        let execute_latency = Histogram::with_opts(HistogramOpts::new(
+            "gmti_runner_execute_seconds",
+            "Wall-clock latency of Runner::execute, in seconds",
+        ))
+        .expect("valid histogram opts");
+        let range_stage_latency = Histogram::with_opts(HistogramOpts::new(
+            "gmti_range_stage_seconds",
+            "Wall-clock latency of RangeStage::execute, in seconds",
+        ))
+        .expect("valid histogram opts");
+        let doppler_stage_latency = Histogram::with_opts(HistogramOpts::new(
+            "gmti_doppler_stage_seconds",
+            "Wall-clock latency of DopplerStage::execute, in seconds",
+        ))
+        .expect("valid histogram opts");
+        let clutter_stage_latency = Histogram::with_opts(HistogramOpts::new(
+            "gmti_clutter_stage_seconds",
+            "Wall-clock latency of ClutterStage::execute, in seconds",
+        ))
+        .expect("valid histogram opts");
+        let last_detection_count = IntGauge::new(
+            "gmti_last_detection_count",
+            "detection_count from the most recently completed run",
+        )
+        .expect("valid gauge opts");
+
+        registry
+            .register(Box::new(requests_total.clone()))
+            .expect("unique metric name");
+        registry
+            .register(Box::new(errors_total.clone()))
+            .expect("unique metric name");
+        registry
+            .register(Box::new(execute_latency.clone()))
+            .expect("unique metric name");
+        registry
+            .register(Box::new(range_stage_latency.clone()))
+            .expect("unique metric name");
+        registry
+            .register(Box::new(doppler_stage_latency.clone()))
+            .expect("unique metric name");
+        registry
+            .register(Box::new(clutter_stage_latency.clone()))
+            .expect("unique metric name");
+        registry
+            .register(Box::new(last_detection_count.clone()))
+            .expect("unique metric name");
+
+        Self {
+            registry,
+            requests_total,
+            errors_total,
+            execute_latency,
+            range_stage_latency,
+            doppler_stage_latency,
+            clutter_stage_latency,
+            last_detection_count,
+        }
+    }
+
+    pub fn record_request(&self) {
+        self.requests_total.inc();
+    }
+
+    pub fn record_error(&self) {
+        self.errors_total.inc();
+    }
+
+    pub fn record_execute(&self, elapsed_secs: f64, timings: &StageTimings) {
+        self.execute_latency.observe(elapsed_secs);
+        self.range_stage_latency.observe(timings.range_secs);
+        self.doppler_stage_latency.observe(timings.doppler_secs);
+        self.clutter_stage_latency.observe(timings.clutter_secs);
+    }
+
+    pub fn set_last_detection_count(&self, count: usize) {
+        self.last_detection_count.set(count as i64);
+    }
+
+    /// Renders the registry in Prometheus text exposition format.
+    pub fn encode(&self) -> Result<String> {
+        let metric_families = self.registry.gather();
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&metric_families, &mut buffer)
+            .context("encoding prometheus metrics")?;
+        String::from_utf8(buffer).context("prometheus output was not valid utf-8")
+    }
+}
+
+impl Default for PipelineMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_registered_metrics() {
+        let metrics = PipelineMetrics::new();
+        metrics.record_request();
+        metrics.set_last_detection_count(12);
+
+        let rendered = metrics.encode().unwrap();
+        assert!(rendered.contains("gmti_ingest_requests_total 1"));
+        assert!(rendered.contains("gmti_last_detection_count 12"));
+    }
+
+    #[test]
+    fn records_stage_timings() {
+        let metrics = PipelineMetrics::new();
+        metrics.record_execute(
+            0.05,
+            &StageTimings {
+                range_secs: 0.01,
+                doppler_secs: 0.02,
+                clutter_secs: 0.015,
+            },
+        );
+
+        assert_eq!(metrics.execute_latency.get_sample_count(), 1);
+        assert_eq!(metrics.range_stage_latency.get_sample_count(), 1);
+    }
+}
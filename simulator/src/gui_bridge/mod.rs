@@ -0,0 +1,6 @@
+pub mod auth;
+pub mod bridge;
+pub mod config;
+pub mod history;
+pub mod metrics;
+pub mod model;
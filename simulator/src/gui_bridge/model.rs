@@ -9,6 +9,11 @@ pub struct VisualizationModel {
     pub detection_records: Vec<DetectionRecord>,
     pub detection_notes: Vec<String>,
     pub scenario_metadata: Option<ScenarioMetadata>,
+    /// Flattened `range_doppler_range_bins × range_doppler_doppler_bins`
+    /// magnitude matrix, row-major by range bin, for the heatmap view.
+    pub range_doppler_matrix: Vec<f32>,
+    pub range_doppler_range_bins: usize,
+    pub range_doppler_doppler_bins: usize,
 }
 
 #[allow(dead_code)]
@@ -20,6 +25,9 @@ impl VisualizationModel {
             detection_records: Vec::new(),
             detection_notes: Vec::new(),
             scenario_metadata: None,
+            range_doppler_matrix: Vec::new(),
+            range_doppler_range_bins: 0,
+            range_doppler_doppler_bins: 0,
         }
     }
 }
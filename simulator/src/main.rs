@@ -1,21 +1,36 @@
 use anyhow::Context;
 use clap::Parser;
-use generator::profile::build_pri_payload;
+use generator::profile::{build_pri_payload, build_pri_payload_from_config, GeneratorConfig};
+use gmticore::prelude::ComputeBackend;
+use gmticore::telemetry::mavlink::{self, MavlinkSource};
 use gui_bridge::bridge::GuiBridge;
 use gui_bridge::model::VisualizationModel;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 use tokio::runtime::Builder as TokioBuilder;
 use tokio::signal;
+use workflow::checkpoint::CheckpointManager;
 use workflow::config::WorkflowConfig;
 use workflow::runner::Runner;
+use workflow::settings::Settings;
 
 mod generator;
 mod gui_bridge;
 mod workflow;
 
+/// Where `CheckpointManager` persists its watermark, alongside the offline
+/// detection log in `tools/data/`.
+const CHECKPOINT_PATH: &str = "tools/data/checkpoint.json";
+
+// This binary is the hosted workstation driver and always links `std` (file
+// I/O for the offline report, `tokio` for Ctrl+C handling, the HTTP bridge);
+// it's not the embedded target `gmticore`'s new `no_std` + `alloc` build
+// targets. Depend on `gmticore` with its default `std` feature enabled here.
+
 #[derive(Parser)]
 #[command(author, version, about = "Rust-facing GMTI workflow driver")]
 struct Args {
@@ -34,6 +49,28 @@ struct Args {
     /// Keep the GUI bridge alive for incoming real-time payloads
     #[arg(long, default_value_t = false)]
     serve: bool,
+    /// UDP address (host:port) of a MAVLink stream driving the generator's
+    /// platform state, e.g. "127.0.0.1:14550". Requires `--serve`.
+    #[arg(long)]
+    mavlink_udp: Option<String>,
+    /// Serial port path of a MAVLink stream driving the generator's
+    /// platform state, e.g. "/dev/ttyUSB0". Requires `--serve`.
+    #[arg(long)]
+    mavlink_serial: Option<String>,
+    #[arg(long, default_value_t = 57_600)]
+    mavlink_baud: u32,
+    /// Prefer the GPU compute path for `RangeStage` and `DopplerStage`;
+    /// falls back to the CPU path when the `gpu` feature is off or no
+    /// adapter is available. Ignored whenever `--workflow` is given; the
+    /// YAML's `backend` (CPU when omitted) applies instead.
+    #[arg(long, default_value_t = false)]
+    gpu: bool,
+    /// Resume a long `--serve` acquisition from the last persisted
+    /// checkpoint (`tools/data/checkpoint.json`) instead of starting with
+    /// an empty watermark. CPIs timestamped at or before the resumed
+    /// watermark are skipped rather than reprocessed.
+    #[arg(long, default_value_t = false)]
+    resume: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -43,11 +80,30 @@ fn main() -> anyhow::Result<()> {
     let workflow_config = if let Some(path) = args.workflow {
         WorkflowConfig::load(path)?
     } else {
-        WorkflowConfig::from_args(args.taps, args.range_bins, args.doppler_bins)
+        let mut config = WorkflowConfig::from_args(args.taps, args.range_bins, args.doppler_bins);
+        if args.gpu {
+            config.backend = ComputeBackend::Gpu;
+        }
+        config
     };
 
-    let runner = Runner::new(workflow_config.clone());
-    let gui_bridge = GuiBridge::new(Arc::new(runner.clone()));
+    let generator_config = GeneratorConfig {
+        taps: workflow_config.taps,
+        range_bins: workflow_config.range_bins,
+        doppler_bins: workflow_config.doppler_bins,
+        ..Default::default()
+    };
+    let settings = Settings::new(workflow_config.clone(), generator_config);
+    let mut runner = Runner::with_settings(settings.clone());
+    if args.serve {
+        let checkpoint = if args.resume {
+            CheckpointManager::load(CHECKPOINT_PATH)
+        } else {
+            CheckpointManager::new(CHECKPOINT_PATH)
+        };
+        runner = runner.with_checkpoint(Arc::new(checkpoint));
+    }
+    let gui_bridge = Arc::new(GuiBridge::new(Arc::new(runner.clone())));
     let payload = build_pri_payload(workflow_config.taps, workflow_config.range_bins)?;
 
     if args.offline {
@@ -66,6 +122,9 @@ fn main() -> anyhow::Result<()> {
             detection_records: result.detection_records.clone(),
             detection_notes: result.doppler_notes.clone(),
             scenario_metadata: result.scenario_metadata.clone(),
+            range_doppler_matrix: result.range_doppler_matrix.clone(),
+            range_doppler_range_bins: result.range_bins,
+            range_doppler_doppler_bins: result.doppler_bins,
         };
 
         gui_bridge.publish(&model)?;
@@ -89,6 +148,72 @@ fn main() -> anyhow::Result<()> {
         file.write_all(report.as_bytes())?;
     }
     if args.serve {
+        let mavlink_source = match (args.mavlink_udp, args.mavlink_serial) {
+            (Some(addr), _) => Some(MavlinkSource::Udp(addr)),
+            (None, Some(path)) => Some(MavlinkSource::Serial {
+                path,
+                baud: args.mavlink_baud,
+            }),
+            (None, None) => None,
+        };
+
+        if let Some(source) = mavlink_source {
+            let platform_state = mavlink::connect(source).context("opening MAVLink stream")?;
+            let runner = runner.clone();
+            let gui_bridge = gui_bridge.clone();
+            let settings = settings.clone();
+            thread::spawn(move || loop {
+                // Re-reads generator()/workflow() every burst rather than
+                // caching them at thread spawn, so a SettingsCommand the
+                // operator applies mid-run — noise/clutter_level/
+                // snr_target_db, but also stage/taps, stage/range_bins, and
+                // stage/doppler_bins, which the generated payload's shape
+                // must keep matching the stage pipeline's — takes effect on
+                // the very next burst.
+                let workflow = settings.workflow();
+                let mut config = settings.generator();
+                config.taps = workflow.taps;
+                config.range_bins = workflow.range_bins;
+                config.doppler_bins = workflow.doppler_bins;
+                config.apply_platform_state(&platform_state.snapshot());
+
+                if runner.checkpoint_should_skip(config.timestamp_start) {
+                    thread::sleep(Duration::from_secs(1));
+                    continue;
+                }
+
+                let published = build_pri_payload_from_config(&config)
+                    .and_then(|payload| runner.execute(&payload).map(|result| (payload, result)));
+                match published {
+                    Ok((payload, result)) => {
+                        let model = VisualizationModel {
+                            power_profile: result.power_profile,
+                            detection_count: result.detection_count,
+                            detection_records: result.detection_records,
+                            detection_notes: result.doppler_notes,
+                            scenario_metadata: payload.ancillary.metadata,
+                            range_doppler_matrix: result.range_doppler_matrix,
+                            range_doppler_range_bins: result.range_bins,
+                            range_doppler_doppler_bins: result.doppler_bins,
+                        };
+                        if let Err(err) = gui_bridge.publish(&model) {
+                            eprintln!("[MAVLink] failed to publish platform-driven burst: {err}");
+                        }
+                        if let Some(watermark) = runner.checkpoint_watermark() {
+                            gui_bridge.publish_status(&format!(
+                                "watermark: block {} @ t={:.3}",
+                                watermark.block_index, watermark.timestamp
+                            ));
+                        }
+                    }
+                    Err(err) => eprintln!("[MAVLink] failed to build platform-driven burst: {err}"),
+                }
+
+                thread::sleep(Duration::from_secs(1));
+            });
+            gui_bridge.publish_status("MAVLink-driven generator running alongside the bridge.");
+        }
+
         gui_bridge.publish_status("HTTP bridge running (Ctrl+C to stop)...");
         let runtime = TokioBuilder::new_current_thread()
             .enable_all()
@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// A monotonic processed-CPI marker: the timestamp of the most recently
+/// *durably delivered* burst and that burst's zero-based index among those
+/// `CheckpointManager` has advanced past since it started tracking.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Watermark {
+    pub timestamp: f64,
+    pub block_index: u64,
+}
+
+/// Tracks a resumable streaming run's processed-CPI watermark and persists
+/// it alongside the offline detection log, so a crashed `--serve` process
+/// can pick up roughly where it left off instead of reprocessing (and
+/// re-publishing) everything from the start.
+///
+/// The watermark only advances once `Runner::execute`/`execute_parallel`
+/// confirms a CPI's detections were accepted by every subscribed
+/// `DetectionSink` — see `DetectionHub::publish`'s return value — so a
+/// lagging or failing sink can't let the watermark run ahead of what's
+/// actually been delivered.
+pub struct CheckpointManager {
+    path: PathBuf,
+    watermark: Mutex<Option<Watermark>>,
+}
+
+impl CheckpointManager {
+    /// Builds a manager with no prior watermark, persisting to `path` from
+    /// the first accepted CPI onward. Prefer `load` for a `--resume` run.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            watermark: Mutex::new(None),
+        }
+    }
+
+    /// Reads the last persisted `Watermark` from `path`, if any, so a
+    /// `--resume` run starts from where the previous one left off. A
+    /// missing or unparseable file is treated as "no checkpoint yet"
+    /// rather than an error — a fresh deployment shouldn't have to create
+    /// one up front.
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let watermark = fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok());
+        Self {
+            path,
+            watermark: Mutex::new(watermark),
+        }
+    }
+
+    /// The most recently persisted watermark, for `--resume` skip checks
+    /// and for status reporting (e.g. `GuiBridge::publish_status`).
+    pub fn watermark(&self) -> Option<Watermark> {
+        self.watermark.lock().ok().and_then(|guard| *guard)
+    }
+
+    /// Whether a CPI timestamped `timestamp` was already processed and
+    /// durably delivered before this run started — i.e. the driver loop
+    /// should skip executing it again after a `--resume`.
+    pub fn should_skip(&self, timestamp: f64) -> bool {
+        self.watermark()
+            .is_some_and(|watermark| timestamp <= watermark.timestamp)
+    }
+
+    /// Advances the watermark past `timestamp` and flushes it to disk, but
+    /// only when `accepted` — every subscribed sink durably took delivery
+    /// of this CPI's detections. A rejected/queued CPI leaves the watermark
+    /// untouched so a crash before it's truly delivered doesn't get skipped
+    /// on the next `--resume`.
+    pub fn record_if_accepted(&self, timestamp: f64, accepted: bool) {
+        if !accepted {
+            return;
+        }
+        let Ok(mut guard) = self.watermark.lock() else {
+            return;
+        };
+        let next = Watermark {
+            timestamp: guard.map_or(timestamp, |w| w.timestamp.max(timestamp)),
+            block_index: guard.map_or(0, |w| w.block_index + 1),
+        };
+        *guard = Some(next);
+        self.flush(&next);
+    }
+
+    fn flush(&self, watermark: &Watermark) {
+        if let Some(parent) = self.path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(contents) = serde_json::to_string(watermark) {
+            let _ = fs::write(&self.path, contents);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+
+    fn temp_path() -> PathBuf {
+        NamedTempFile::new().unwrap().into_temp_path().to_path_buf()
+    }
+
+    #[test]
+    fn fresh_manager_has_no_watermark() {
+        let manager = CheckpointManager::new(temp_path());
+        assert_eq!(manager.watermark(), None);
+        assert!(!manager.should_skip(0.0));
+    }
+
+    #[test]
+    fn record_if_accepted_advances_and_persists_the_watermark() {
+        let path = temp_path();
+        let manager = CheckpointManager::new(&path);
+
+        manager.record_if_accepted(10.0, true);
+        let watermark = manager.watermark().unwrap();
+        assert_eq!(watermark.timestamp, 10.0);
+        assert_eq!(watermark.block_index, 0);
+
+        let resumed = CheckpointManager::load(&path);
+        assert_eq!(resumed.watermark(), Some(watermark));
+        assert!(resumed.should_skip(5.0));
+        assert!(!resumed.should_skip(15.0));
+    }
+
+    #[test]
+    fn rejected_batch_does_not_advance_the_watermark() {
+        let manager = CheckpointManager::new(temp_path());
+        manager.record_if_accepted(10.0, true);
+        manager.record_if_accepted(20.0, false);
+
+        let watermark = manager.watermark().unwrap();
+        assert_eq!(watermark.timestamp, 10.0);
+        assert_eq!(watermark.block_index, 0);
+    }
+}
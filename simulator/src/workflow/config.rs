@@ -1,5 +1,6 @@
 use anyhow::Context;
-use gmticore::prelude::StageConfig;
+use gmticore::prelude::{ComputeBackend, StageConfig};
+use gmticore::telemetry::MqttConfig;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
@@ -9,6 +10,35 @@ pub struct WorkflowConfig {
     pub taps: usize,
     pub range_bins: usize,
     pub doppler_bins: usize,
+    /// Broker/client settings for streaming each run's detections, power
+    /// profile, and Doppler notes over MQTT. `None` skips MQTT entirely.
+    #[serde(default)]
+    pub mqtt: Option<MqttConfig>,
+    /// `CfarStage` guard-cell half-width `G`.
+    #[serde(default = "default_cfar_guard_cells")]
+    pub cfar_guard_cells: usize,
+    /// `CfarStage` training-cell half-width `T`.
+    #[serde(default = "default_cfar_training_cells")]
+    pub cfar_training_cells: usize,
+    /// `CfarStage` target probability of false alarm `P_fa`.
+    #[serde(default = "default_cfar_false_alarm_rate")]
+    pub cfar_false_alarm_rate: f32,
+    /// Preferred `DopplerStage` compute backend; falls back to `Cpu` when
+    /// the `gpu` feature is off or no adapter is available.
+    #[serde(default)]
+    pub backend: ComputeBackend,
+}
+
+fn default_cfar_guard_cells() -> usize {
+    2
+}
+
+fn default_cfar_training_cells() -> usize {
+    8
+}
+
+fn default_cfar_false_alarm_rate() -> f32 {
+    1e-3
 }
 
 impl WorkflowConfig {
@@ -26,6 +56,11 @@ impl WorkflowConfig {
             taps,
             range_bins,
             doppler_bins,
+            mqtt: None,
+            cfar_guard_cells: default_cfar_guard_cells(),
+            cfar_training_cells: default_cfar_training_cells(),
+            cfar_false_alarm_rate: default_cfar_false_alarm_rate(),
+            backend: ComputeBackend::default(),
         }
     }
 
@@ -34,6 +69,10 @@ impl WorkflowConfig {
             taps: self.taps,
             range_bins: self.range_bins,
             doppler_bins: self.doppler_bins,
+            cfar_guard_cells: self.cfar_guard_cells,
+            cfar_training_cells: self.cfar_training_cells,
+            cfar_false_alarm_rate: self.cfar_false_alarm_rate,
+            backend: self.backend,
         }
     }
 }
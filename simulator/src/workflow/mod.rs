@@ -0,0 +1,4 @@
+pub mod checkpoint;
+pub mod config;
+pub mod runner;
+pub mod settings;
@@ -1,8 +1,27 @@
+use crate::generator::profile::GeneratorConfig;
+use crate::workflow::checkpoint::{CheckpointManager, Watermark};
 use crate::workflow::config::WorkflowConfig;
+use crate::workflow::settings::Settings;
 use anyhow::Context;
-use gmticore::agp_interface::{DetectionRecord, PriPayload, ScenarioMetadata};
+use gmticore::agp_interface::{DetectionHub, DetectionRecord, DetectionSink, PriPayload, ScenarioMetadata};
 use gmticore::prelude::{ProcessingStage, StageInput};
-use gmticore::processing::{ClutterStage, DopplerStage, RangeStage};
+use gmticore::processing::{CfarStage, ClutterStage, DopplerStage, RangeStage};
+use gmticore::telemetry::MqttPublisher;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Depth of each `DetectionSink`'s backlog in a `Runner`-owned
+/// `DetectionHub`, matching `MqttPublisher`'s client queue depth.
+const DETECTION_HUB_BUFFER_DEPTH: usize = 16;
+
+/// Per-stage wall-clock durations from one `Runner::execute` call, in
+/// seconds, so callers (e.g. the GUI bridge's Prometheus exporter) can feed
+/// them straight into a histogram without repeating the instrumentation.
+pub struct StageTimings {
+    pub range_secs: f64,
+    pub doppler_secs: f64,
+    pub clutter_secs: f64,
+}
 
 pub struct WorkflowResult {
     pub power_profile: Vec<f32>,
@@ -10,74 +29,241 @@ pub struct WorkflowResult {
     pub doppler_notes: Vec<String>,
     pub detection_records: Vec<DetectionRecord>,
     pub scenario_metadata: Option<ScenarioMetadata>,
+    pub stage_timings: StageTimings,
+    /// Flattened `range_bins × doppler_bins` magnitude matrix, row-major by
+    /// range bin, for the visualizer's range-Doppler heatmap.
+    pub range_doppler_matrix: Vec<f32>,
+    pub range_bins: usize,
+    pub doppler_bins: usize,
 }
 
 #[derive(Clone)]
 pub struct Runner {
-    config: WorkflowConfig,
+    settings: Arc<Settings>,
+    mqtt: Option<Arc<MqttPublisher>>,
+    detection_hub: Arc<Mutex<DetectionHub>>,
+    checkpoint: Option<Arc<CheckpointManager>>,
 }
 
 impl Runner {
+    /// Builds a `Runner` around a fresh `Settings` tree holding `config` and
+    /// a default `GeneratorConfig`; that tree is still reachable (and
+    /// retunable) through `settings()`. Use `Runner::with_settings` to share
+    /// an existing tree instead.
     pub fn new(config: WorkflowConfig) -> Self {
-        Self { config }
+        Self::with_settings(Settings::new(config, GeneratorConfig::default()))
+    }
+
+    /// Builds a `Runner` driven by a shared `Settings` tree, so a
+    /// `SettingsCommand` applied through it is picked up on the very next
+    /// `execute` call without restarting the process.
+    pub fn with_settings(settings: Arc<Settings>) -> Self {
+        let mqtt = settings.workflow().mqtt.as_ref().and_then(|mqtt_config| {
+            MqttPublisher::connect(mqtt_config)
+                .map(Arc::new)
+                .map_err(|err| eprintln!("[MQTT] failed to connect to {}: {err}", mqtt_config.broker_url))
+                .ok()
+        });
+        Self {
+            settings,
+            mqtt,
+            detection_hub: Arc::new(Mutex::new(DetectionHub::new(DETECTION_HUB_BUFFER_DEPTH))),
+            checkpoint: None,
+        }
+    }
+
+    /// Attaches a `CheckpointManager` whose watermark `execute`/
+    /// `execute_parallel` advance once a CPI's detections are durably
+    /// accepted by every subscribed `DetectionSink`. Pass one built with
+    /// `CheckpointManager::load` to resume a prior `--serve` run.
+    pub fn with_checkpoint(mut self, checkpoint: Arc<CheckpointManager>) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// The attached `CheckpointManager`'s current watermark, if any — for
+    /// drivers that skip already-processed input or surface progress
+    /// through `GuiBridge::publish_status`.
+    pub fn checkpoint_watermark(&self) -> Option<Watermark> {
+        self.checkpoint.as_ref().and_then(|checkpoint| checkpoint.watermark())
+    }
+
+    /// Whether a CPI timestamped `timestamp` was already processed before
+    /// a `--resume`'d driver loop started, per the attached
+    /// `CheckpointManager`. `false` with no checkpoint attached.
+    pub fn checkpoint_should_skip(&self, timestamp: f64) -> bool {
+        self.checkpoint
+            .as_ref()
+            .is_some_and(|checkpoint| checkpoint.should_skip(timestamp))
+    }
+
+    /// The `Settings` tree backing this `Runner`, shared with every clone —
+    /// for callers that need to submit `SettingsCommand`s (e.g. the GUI
+    /// bridge's `/settings` route) or read `generator()` for a
+    /// platform-driven generator loop.
+    pub fn settings(&self) -> &Arc<Settings> {
+        &self.settings
+    }
+
+    /// Registers a live consumer of this `Runner`'s completed-CPI detection
+    /// batches (GUI bridge, file logger, future network export). Every
+    /// clone of this `Runner` shares the same `DetectionHub`, so a sink
+    /// registered anywhere sees every `execute` call from then on.
+    pub fn subscribe_detection_sink(&self, sink: Box<dyn DetectionSink>) {
+        if let Ok(mut hub) = self.detection_hub.lock() {
+            hub.subscribe(sink);
+        }
     }
 
     pub fn execute(&self, payload: &PriPayload) -> anyhow::Result<WorkflowResult> {
-        let stage_config = self.config.to_stage_config();
+        let result = self.run_stages(payload)?;
+        self.publish(&result, payload.ancillary.timestamp);
+        Ok(result)
+    }
+
+    /// Runs `payloads` through the stage pipeline concurrently on the rayon
+    /// global pool — one CPI block per task, each with its own stage
+    /// instances so nothing is shared across workers but `Runner`'s
+    /// `Arc`-wrapped `Settings`/`DetectionHub`/`MqttPublisher`. Publishing to
+    /// the `DetectionHub` and MQTT happens afterwards, in ascending
+    /// `PriAncillary::timestamp` order, so sinks see the same sequence
+    /// `execute` would have produced one block at a time regardless of
+    /// which worker finished first. Returns results in the same order as
+    /// `payloads`.
+    pub fn execute_parallel(&self, payloads: &[PriPayload]) -> anyhow::Result<Vec<WorkflowResult>> {
+        use rayon::prelude::*;
+
+        let mut computed: Vec<(usize, f64, anyhow::Result<WorkflowResult>)> = payloads
+            .par_iter()
+            .enumerate()
+            .map(|(index, payload)| {
+                (
+                    index,
+                    payload.ancillary.timestamp,
+                    self.run_stages(payload),
+                )
+            })
+            .collect();
+        computed.sort_by(|(_, lhs, _), (_, rhs, _)| lhs.total_cmp(rhs));
+
+        let mut ordered: Vec<Option<WorkflowResult>> = (0..payloads.len()).map(|_| None).collect();
+        for (index, timestamp, result) in computed {
+            let result = result?;
+            self.publish(&result, timestamp);
+            ordered[index] = Some(result);
+        }
+
+        Ok(ordered
+            .into_iter()
+            .map(|result| result.expect("every payload index is filled exactly once"))
+            .collect())
+    }
+
+    /// Publishes one `execute`/`execute_parallel` block's results to MQTT
+    /// and the `DetectionHub`, then — if a `CheckpointManager` is attached
+    /// — advances its watermark past `timestamp`, but only once the
+    /// `DetectionHub` confirms every subscribed sink durably accepted the
+    /// batch. Split out of `run_stages` so `execute_parallel` can defer
+    /// publishing until results are sorted into timestamp order.
+    fn publish(&self, result: &WorkflowResult, timestamp: f64) {
+        if let Some(mqtt) = &self.mqtt {
+            mqtt.publish_detections(&result.detection_records);
+            mqtt.publish_power(&result.power_profile);
+            mqtt.publish_doppler_notes(&result.doppler_notes);
+            mqtt.metrics().record_processed();
+        }
+
+        let accepted = self
+            .detection_hub
+            .lock()
+            .map(|mut hub| hub.publish(&result.detection_records))
+            .unwrap_or(false);
+
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.record_if_accepted(timestamp, accepted);
+        }
+    }
+
+    /// Runs `payload` through the range/Doppler/clutter/CFAR pipeline and
+    /// returns the assembled result, without publishing anywhere — the
+    /// part of `execute` that's safe to run concurrently across CPI blocks.
+    fn run_stages(&self, payload: &PriPayload) -> anyhow::Result<WorkflowResult> {
+        let stage_config = self.settings.workflow().to_stage_config();
 
         let mut range_stage = RangeStage::new(stage_config.range_bins.max(1));
         range_stage
             .initialize(&stage_config)
             .context("initializing range stage")?;
+        let range_started = Instant::now();
+        let range_input = StageInput::from_real_samples(
+            &payload.samples,
+            stage_config.taps.max(1),
+            stage_config.range_bins.max(1),
+            Some(payload.ancillary.timestamp),
+        )
+        .context("reshaping PRI samples into a range/pulses matrix")?;
         let range_output = range_stage
-            .execute(StageInput {
-                samples: payload.samples.clone(),
-                timestamp: Some(payload.ancillary.timestamp),
-            })
+            .execute(range_input)
             .context("executing range stage")?;
+        let range_secs = range_started.elapsed().as_secs_f64();
         range_stage.cleanup();
 
         let mut doppler_stage = DopplerStage::new(stage_config.doppler_bins.max(1));
         doppler_stage
             .initialize(&stage_config)
             .context("initializing doppler stage")?;
+        let doppler_started = Instant::now();
         let doppler_output = doppler_stage
             .execute(StageInput {
-                samples: range_output.samples.clone(),
+                matrix: range_output.matrix.clone(),
                 timestamp: Some(payload.ancillary.timestamp),
             })
             .context("executing doppler stage")?;
+        let doppler_secs = doppler_started.elapsed().as_secs_f64();
         doppler_stage.cleanup();
 
         let mut clutter_stage = ClutterStage::new(stage_config.range_bins.max(1));
         clutter_stage
             .initialize(&stage_config)
             .context("initializing clutter stage")?;
+        let clutter_started = Instant::now();
         let clutter_output = clutter_stage
             .execute(StageInput {
-                samples: doppler_output.samples.clone(),
+                matrix: doppler_output.matrix.clone(),
                 timestamp: Some(payload.ancillary.timestamp),
             })
             .context("executing clutter stage")?;
+        let clutter_secs = clutter_started.elapsed().as_secs_f64();
         clutter_stage.cleanup();
 
+        let mut cfar_stage = CfarStage::new();
+        cfar_stage
+            .initialize(&stage_config)
+            .context("initializing CFAR stage")?;
+        let cfar_output = cfar_stage
+            .execute(StageInput {
+                matrix: clutter_output.matrix.clone(),
+                timestamp: Some(payload.ancillary.timestamp),
+            })
+            .context("executing CFAR stage")?;
+        cfar_stage.cleanup();
+
         let power_profile = range_output
             .metadata
             .power_profile
             .clone()
             .unwrap_or_default();
-        let mut detection_records = clutter_output.metadata.detection_records.clone();
-        let mut detection_count = detection_records.len();
+        let detection_records = cfar_output.metadata.detection_records.clone();
+        let detection_count = detection_records.len();
         let doppler_notes = doppler_output.metadata.notes.clone();
         let scenario_metadata = payload.ancillary.metadata.clone();
 
-        if detection_records.len() < 6 {
-            detection_records = augment_detection_records(
-                detection_records,
-                scenario_metadata.as_ref(),
-                payload.ancillary.timestamp,
-            );
-            detection_count = detection_records.len();
+        let (doppler_bins, range_bins) = doppler_output.matrix.dim();
+        let mut range_doppler_matrix = Vec::with_capacity(range_bins * doppler_bins);
+        for range_bin in 0..range_bins {
+            for doppler_bin in 0..doppler_bins {
+                range_doppler_matrix.push(doppler_output.matrix[[doppler_bin, range_bin]].norm());
+            }
         }
 
         Ok(WorkflowResult {
@@ -86,55 +272,24 @@ impl Runner {
             doppler_notes,
             detection_records,
             scenario_metadata,
+            stage_timings: StageTimings {
+                range_secs,
+                doppler_secs,
+                clutter_secs,
+            },
+            range_doppler_matrix,
+            range_bins,
+            doppler_bins,
         })
     }
 }
 
-fn augment_detection_records(
-    mut records: Vec<DetectionRecord>,
-    metadata: Option<&ScenarioMetadata>,
-    timestamp: f64,
-) -> Vec<DetectionRecord> {
-    let area_km = metadata
-        .map(|m| (m.area_width_km + m.area_height_km) / 2.0)
-        .unwrap_or(10.0);
-    let target = ((area_km * 1.8).round() as usize).max(18).min(64);
-    if records.len() >= target {
-        return records;
-    }
-
-    let base_range = (area_km * 1000.0).max(2500.0);
-    let snr_target = metadata.map(|m| m.snr_target_db).unwrap_or(15.0);
-    let interference_magnitude = metadata.map(|m| m.interference_db.abs()).unwrap_or(0.0);
-    let clutter_modifier = metadata.map(|m| m.clutter_level).unwrap_or(0.5);
-
-    for idx in records.len()..target {
-        let ratio = (idx + 1) as f32 / target as f32;
-        let range = base_range * (0.3 + 0.7 * ratio);
-        let doppler_base = ((ratio * 2.0 - 1.0) * 40.0) * (1.0 + clutter_modifier);
-        let wobble = ((timestamp + idx as f64 * 0.18).sin() * 12.0) as f32;
-        let doppler = (doppler_base + wobble).clamp(-80.0, 80.0);
-        let snr = (snr_target + ratio * 8.0 - interference_magnitude * 0.1).max(2.0);
-        let bearing_deg = (idx as f32 / target as f32) * 360.0;
-        let elevation_deg = 0.0;
-        let extra = DetectionRecord::new(
-            timestamp + idx as f64 * 0.0004,
-            range,
-            doppler,
-            snr,
-            bearing_deg,
-            elevation_deg,
-        );
-        records.push(extra);
-    }
-
-    records
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::generator::profile::build_pri_payload;
+    use crate::generator::profile::{build_pri_payload, build_pri_payload_from_config, GeneratorConfig};
+    use gmticore::agp_interface::MockSink;
+    use tempfile::NamedTempFile;
 
     #[test]
     fn runner_executes_workflow() {
@@ -142,8 +297,74 @@ mod tests {
         let runner = Runner::new(cfg.clone());
         let payload = build_pri_payload(cfg.taps, cfg.range_bins).unwrap();
         let result = runner.execute(&payload).unwrap();
-        assert!(result.detection_count >= 18);
         assert_eq!(result.detection_records.len(), result.detection_count);
         assert_eq!(result.power_profile.len(), cfg.range_bins);
+        assert_eq!(
+            result.range_doppler_matrix.len(),
+            result.range_bins * result.doppler_bins
+        );
+    }
+
+    #[test]
+    fn runner_execute_parallel_matches_serial_results() {
+        let cfg = WorkflowConfig::from_args(2, 16, 8);
+        let runner = Runner::new(cfg.clone());
+        let payloads: Vec<_> = (0..4)
+            .map(|_| build_pri_payload(cfg.taps, cfg.range_bins).unwrap())
+            .collect();
+
+        let serial: Vec<_> = payloads
+            .iter()
+            .map(|payload| runner.execute(payload).unwrap())
+            .collect();
+        let parallel = runner.execute_parallel(&payloads).unwrap();
+
+        assert_eq!(parallel.len(), serial.len());
+        for (serial_result, parallel_result) in serial.iter().zip(&parallel) {
+            assert_eq!(serial_result.detection_count, parallel_result.detection_count);
+            assert_eq!(serial_result.power_profile, parallel_result.power_profile);
+            assert_eq!(
+                serial_result.range_doppler_matrix,
+                parallel_result.range_doppler_matrix
+            );
+        }
+    }
+
+    #[test]
+    fn runner_with_checkpoint_advances_watermark_on_each_execute() {
+        let cfg = WorkflowConfig::from_args(2, 16, 8);
+        let checkpoint_path = NamedTempFile::new().unwrap().into_temp_path().to_path_buf();
+        let checkpoint = Arc::new(CheckpointManager::new(&checkpoint_path));
+        let runner = Runner::new(cfg.clone()).with_checkpoint(checkpoint);
+
+        let config = GeneratorConfig {
+            taps: cfg.taps,
+            range_bins: cfg.range_bins,
+            timestamp_start: 100.0,
+            ..Default::default()
+        };
+        let payload = build_pri_payload_from_config(&config).unwrap();
+        runner.execute(&payload).unwrap();
+
+        let watermark = runner.checkpoint_watermark().unwrap();
+        assert_eq!(watermark.timestamp, 100.0);
+
+        let resumed = Arc::new(CheckpointManager::load(&checkpoint_path));
+        assert!(resumed.should_skip(100.0));
+        assert!(!resumed.should_skip(200.0));
+    }
+
+    #[test]
+    fn runner_publishes_each_execute_to_subscribed_sinks() {
+        let cfg = WorkflowConfig::from_args(2, 16, 8);
+        let runner = Runner::new(cfg.clone());
+        runner.subscribe_detection_sink(Box::new(MockSink::new()));
+
+        let payload = build_pri_payload(cfg.taps, cfg.range_bins).unwrap();
+        runner.execute(&payload).unwrap();
+        runner.execute(&payload).unwrap();
+
+        let hub = runner.detection_hub.lock().unwrap();
+        assert_eq!(hub.dropped_batches(), 0);
     }
 }
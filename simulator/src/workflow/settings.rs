@@ -0,0 +1,226 @@
+use crate::generator::profile::GeneratorConfig;
+use crate::workflow::config::WorkflowConfig;
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+/// A value settable on one leaf of the settings tree. New leaf types should
+/// add a variant here rather than widen an existing one, so `Settings::apply`
+/// stays an exhaustive, type-checked match per path.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SettingValue {
+    UInt(usize),
+    Float(f32),
+}
+
+/// A single `path = value` mutation submitted on the settings command
+/// channel, e.g. `{ path: "stage/doppler_bins", value: UInt(256) }`. Also
+/// the JSON body `GuiBridge`'s `POST /settings` route accepts, so operators
+/// can drive the same channel over HTTP.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsCommand {
+    pub path: String,
+    pub value: SettingValue,
+}
+
+impl SettingsCommand {
+    pub fn new(path: impl Into<String>, value: SettingValue) -> Self {
+        Self {
+            path: path.into(),
+            value,
+        }
+    }
+}
+
+/// Hierarchical, runtime-mutable view over the `WorkflowConfig` (the
+/// `stage/*` paths) and `GeneratorConfig` (the `generator/*` paths) driving
+/// a run. `Runner::execute` reads a fresh `StageConfig` from here on every
+/// call rather than caching one at construction time, so a mutation is
+/// picked up — and its stages rebuilt via `cleanup` + `initialize` — on the
+/// very next burst without restarting the process.
+pub struct Settings {
+    workflow: RwLock<WorkflowConfig>,
+    generator: RwLock<GeneratorConfig>,
+}
+
+impl Settings {
+    pub fn new(workflow: WorkflowConfig, generator: GeneratorConfig) -> Arc<Self> {
+        Arc::new(Self {
+            workflow: RwLock::new(workflow),
+            generator: RwLock::new(generator),
+        })
+    }
+
+    pub fn workflow(&self) -> WorkflowConfig {
+        self.workflow
+            .read()
+            .expect("settings lock poisoned")
+            .clone()
+    }
+
+    pub fn generator(&self) -> GeneratorConfig {
+        self.generator
+            .read()
+            .expect("settings lock poisoned")
+            .clone()
+    }
+
+    /// Validates and applies one `path = value` leaf mutation, rejecting
+    /// unknown paths, mistyped values, or values outside the leaf's valid
+    /// range without touching any state.
+    pub fn apply(&self, command: &SettingsCommand) -> anyhow::Result<()> {
+        match (command.path.as_str(), command.value) {
+            ("stage/taps", SettingValue::UInt(taps)) => {
+                if taps == 0 {
+                    bail!("stage/taps must be non-zero");
+                }
+                self.workflow.write().expect("settings lock poisoned").taps = taps;
+            }
+            ("stage/range_bins", SettingValue::UInt(range_bins)) => {
+                if range_bins == 0 {
+                    bail!("stage/range_bins must be non-zero");
+                }
+                self.workflow
+                    .write()
+                    .expect("settings lock poisoned")
+                    .range_bins = range_bins;
+            }
+            ("stage/doppler_bins", SettingValue::UInt(doppler_bins)) => {
+                if doppler_bins == 0 {
+                    bail!("stage/doppler_bins must be non-zero");
+                }
+                self.workflow
+                    .write()
+                    .expect("settings lock poisoned")
+                    .doppler_bins = doppler_bins;
+            }
+            ("generator/noise", SettingValue::Float(noise)) => {
+                if !(0.0..=1.0).contains(&noise) {
+                    bail!("generator/noise must be within 0.0..=1.0");
+                }
+                self.generator.write().expect("settings lock poisoned").noise = noise;
+            }
+            ("generator/clutter_level", SettingValue::Float(clutter_level)) => {
+                if !(0.0..=1.0).contains(&clutter_level) {
+                    bail!("generator/clutter_level must be within 0.0..=1.0");
+                }
+                self.generator
+                    .write()
+                    .expect("settings lock poisoned")
+                    .clutter_level = clutter_level;
+            }
+            ("generator/snr_target_db", SettingValue::Float(snr_target_db)) => {
+                self.generator
+                    .write()
+                    .expect("settings lock poisoned")
+                    .snr_target_db = snr_target_db;
+            }
+            (path, value) => bail!("unknown or mistyped settings path `{path}` = {value:?}"),
+        }
+        Ok(())
+    }
+
+    /// Spawns a background thread that drains `commands` and applies each
+    /// one in turn, logging (rather than propagating) validation failures so
+    /// one bad command from an operator doesn't stop later ones from being
+    /// applied.
+    pub fn spawn_command_loop(self: &Arc<Self>, commands: Receiver<SettingsCommand>) {
+        let settings = self.clone();
+        thread::spawn(move || {
+            for command in commands {
+                if let Err(err) = settings.apply(&command) {
+                    eprintln!("[Settings] rejected {}: {err}", command.path);
+                }
+            }
+        });
+    }
+}
+
+/// Builds the `mpsc` channel operators submit `SettingsCommand`s on; the
+/// `Sender` half is handed to whatever exposes live tuning (a CLI REPL, an
+/// HTTP route), and the `Receiver` half is handed to
+/// `Settings::spawn_command_loop`.
+pub fn command_channel() -> (Sender<SettingsCommand>, Receiver<SettingsCommand>) {
+    mpsc::channel()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_settings() -> Arc<Settings> {
+        Settings::new(
+            WorkflowConfig::from_args(4, 1024, 128),
+            GeneratorConfig::default(),
+        )
+    }
+
+    #[test]
+    fn applies_valid_stage_leaf() {
+        let settings = test_settings();
+        settings
+            .apply(&SettingsCommand::new("stage/doppler_bins", SettingValue::UInt(256)))
+            .unwrap();
+        assert_eq!(settings.workflow().doppler_bins, 256);
+    }
+
+    #[test]
+    fn rejects_zero_doppler_bins() {
+        let settings = test_settings();
+        let err = settings
+            .apply(&SettingsCommand::new("stage/doppler_bins", SettingValue::UInt(0)))
+            .unwrap_err();
+        assert!(err.to_string().contains("non-zero"));
+        assert_eq!(settings.workflow().doppler_bins, 128);
+    }
+
+    #[test]
+    fn applies_valid_generator_leaf() {
+        let settings = test_settings();
+        settings
+            .apply(&SettingsCommand::new("generator/noise", SettingValue::Float(0.2)))
+            .unwrap();
+        assert_eq!(settings.generator().noise, 0.2);
+    }
+
+    #[test]
+    fn rejects_out_of_range_noise() {
+        let settings = test_settings();
+        let err = settings
+            .apply(&SettingsCommand::new("generator/noise", SettingValue::Float(2.0)))
+            .unwrap_err();
+        assert!(err.to_string().contains("0.0..=1.0"));
+    }
+
+    #[test]
+    fn rejects_unknown_path() {
+        let settings = test_settings();
+        let err = settings
+            .apply(&SettingsCommand::new("stage/unknown", SettingValue::UInt(1)))
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown"));
+    }
+
+    #[test]
+    fn command_loop_applies_submitted_commands() {
+        let settings = test_settings();
+        let (sender, receiver) = command_channel();
+        settings.spawn_command_loop(receiver);
+        sender
+            .send(SettingsCommand::new("stage/taps", SettingValue::UInt(8)))
+            .unwrap();
+        drop(sender);
+
+        let mut taps = settings.workflow().taps;
+        for _ in 0..50 {
+            if taps == 8 {
+                break;
+            }
+            thread::sleep(std::time::Duration::from_millis(10));
+            taps = settings.workflow().taps;
+        }
+        assert_eq!(taps, 8);
+    }
+}
@@ -0,0 +1,192 @@
+use gmticore::agp_interface::DetectionRecord;
+
+/// Cap on the active-alert log: once it holds more than this many alerts,
+/// the oldest are dropped first so the most recent activity stays visible.
+pub(crate) const MAX_ACTIVE_ALERTS: usize = 100;
+
+/// Operator-defined thresholds evaluated against every incoming
+/// `DetectionRecord` on `Message::PayloadFetched`.
+#[derive(Debug, Clone)]
+pub(crate) struct AlertRules {
+    /// Raise `HighSnr` for any detection at or above this SNR (dB).
+    pub min_snr_db: Option<f32>,
+    /// Raise `KeepOutRange` for any detection at or inside this range (m).
+    pub max_range_m: Option<f32>,
+    /// Raise `ClosingTarget` for any detection closing at or above this
+    /// Doppler (m/s).
+    pub closing_doppler_mps: Option<f32>,
+}
+
+impl Default for AlertRules {
+    fn default() -> Self {
+        Self {
+            min_snr_db: None,
+            max_range_m: None,
+            closing_doppler_mps: None,
+        }
+    }
+}
+
+/// Which threshold a triggered alert corresponds to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AlertRule {
+    HighSnr,
+    KeepOutRange,
+    ClosingTarget,
+}
+
+impl AlertRule {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlertRule::HighSnr => "High SNR",
+            AlertRule::KeepOutRange => "Keep-out range",
+            AlertRule::ClosingTarget => "Closing target",
+        }
+    }
+}
+
+/// How urgently an alert should be surfaced to the operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AlertSeverity {
+    Warning,
+    Critical,
+}
+
+impl AlertSeverity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlertSeverity::Warning => "Warning",
+            AlertSeverity::Critical => "Critical",
+        }
+    }
+
+    pub fn color(&self) -> iced::Color {
+        match self {
+            AlertSeverity::Warning => iced::Color::from_rgb(0.85, 0.65, 0.15),
+            AlertSeverity::Critical => iced::Color::from_rgb(0.85, 0.2, 0.2),
+        }
+    }
+}
+
+/// One triggered (detection, rule) pair, as surfaced via
+/// `Message::AlertRaised` before it is timestamped and logged.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RaisedAlert {
+    pub detection_index: usize,
+    pub rule: AlertRule,
+    pub severity: AlertSeverity,
+}
+
+/// A logged alert in the active-alerts deque, acknowledgeable by the
+/// operator without losing its place in the timeline.
+#[derive(Debug, Clone)]
+pub(crate) struct ActiveAlert {
+    pub id: u64,
+    pub detection_index: usize,
+    pub rule: AlertRule,
+    pub severity: AlertSeverity,
+    pub timestamp: f64,
+    pub acknowledged: bool,
+}
+
+/// Evaluates `rules` against every record, returning one `RaisedAlert` per
+/// triggering (record, rule) pair. A single detection can trigger more than
+/// one rule.
+pub(crate) fn evaluate(records: &[DetectionRecord], rules: &AlertRules) -> Vec<RaisedAlert> {
+    let mut raised = Vec::new();
+    for (detection_index, record) in records.iter().enumerate() {
+        if let Some(min_snr) = rules.min_snr_db {
+            if record.snr >= min_snr {
+                raised.push(RaisedAlert {
+                    detection_index,
+                    rule: AlertRule::HighSnr,
+                    severity: AlertSeverity::Warning,
+                });
+            }
+        }
+        if let Some(max_range) = rules.max_range_m {
+            if record.range <= max_range {
+                raised.push(RaisedAlert {
+                    detection_index,
+                    rule: AlertRule::KeepOutRange,
+                    severity: AlertSeverity::Critical,
+                });
+            }
+        }
+        if let Some(closing_threshold) = rules.closing_doppler_mps {
+            if record.doppler >= closing_threshold {
+                raised.push(RaisedAlert {
+                    detection_index,
+                    rule: AlertRule::ClosingTarget,
+                    severity: AlertSeverity::Warning,
+                });
+            }
+        }
+    }
+    raised
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(range: f32, doppler: f32, snr: f32) -> DetectionRecord {
+        DetectionRecord::new(0.0, range, doppler, snr, 0.0, 0.0)
+    }
+
+    #[test]
+    fn no_rules_raises_nothing() {
+        let rules = AlertRules::default();
+        let records = vec![record(500.0, 20.0, 25.0)];
+        assert!(evaluate(&records, &rules).is_empty());
+    }
+
+    #[test]
+    fn high_snr_rule_triggers_at_threshold() {
+        let rules = AlertRules {
+            min_snr_db: Some(20.0),
+            ..AlertRules::default()
+        };
+        let records = vec![record(500.0, 0.0, 20.0)];
+        let raised = evaluate(&records, &rules);
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].rule, AlertRule::HighSnr);
+    }
+
+    #[test]
+    fn keep_out_range_rule_triggers_inside_radius() {
+        let rules = AlertRules {
+            max_range_m: Some(1000.0),
+            ..AlertRules::default()
+        };
+        let records = vec![record(800.0, 0.0, 5.0), record(1500.0, 0.0, 5.0)];
+        let raised = evaluate(&records, &rules);
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].detection_index, 0);
+        assert_eq!(raised[0].rule, AlertRule::KeepOutRange);
+    }
+
+    #[test]
+    fn closing_target_rule_ignores_receding_detections() {
+        let rules = AlertRules {
+            closing_doppler_mps: Some(10.0),
+            ..AlertRules::default()
+        };
+        let records = vec![record(500.0, -40.0, 5.0), record(500.0, 15.0, 5.0)];
+        let raised = evaluate(&records, &rules);
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].detection_index, 1);
+        assert_eq!(raised[0].rule, AlertRule::ClosingTarget);
+    }
+
+    #[test]
+    fn one_detection_can_trigger_multiple_rules() {
+        let rules = AlertRules {
+            min_snr_db: Some(10.0),
+            max_range_m: Some(1000.0),
+            closing_doppler_mps: Some(10.0),
+        };
+        let records = vec![record(500.0, 20.0, 15.0)];
+        assert_eq!(evaluate(&records, &rules).len(), 3);
+    }
+}
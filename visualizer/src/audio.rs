@@ -0,0 +1,170 @@
+use rodio::{OutputStream, OutputStreamHandle, Sink, Source};
+use std::f32::consts::{FRAC_PI_2, PI};
+use std::time::{Duration, Instant};
+
+/// Length of one spatialized cue.
+const TONE_DURATION: Duration = Duration::from_millis(120);
+const SAMPLE_RATE: u32 = 48_000;
+/// `detection.doppler` (m/s) to tone pitch: closing targets play higher.
+const BASE_FREQUENCY_HZ: f32 = 400.0;
+const DOPPLER_TO_HZ: f32 = 2.0;
+/// Dense frames can't trigger more than one burst of cues per this interval.
+const SONIFY_RATE_LIMIT: Duration = Duration::from_secs(1);
+
+/// Per-detection tone placement: frequency plus equal-power stereo gains.
+#[derive(Debug, Clone, Copy)]
+pub struct ToneCue {
+    pub frequency_hz: f32,
+    pub gain_left: f32,
+    pub gain_right: f32,
+}
+
+impl ToneCue {
+    /// Builds a cue from a detection's Doppler/SNR and its azimuth (radians,
+    /// 0 = ahead, positive = to the right) via equal-power panning.
+    pub fn new(doppler: f32, snr: f32, azimuth_rad: f32) -> Self {
+        let frequency_hz = (BASE_FREQUENCY_HZ + doppler * DOPPLER_TO_HZ).clamp(120.0, 2400.0);
+        let gain = (snr / 30.0).clamp(0.05, 1.0);
+        let theta = ((azimuth_rad + FRAC_PI_2) / PI * FRAC_PI_2).clamp(0.0, FRAC_PI_2);
+        Self {
+            frequency_hz,
+            gain_left: gain * theta.cos(),
+            gain_right: gain * theta.sin(),
+        }
+    }
+}
+
+/// Plays spatialized tone cues for incoming detections, rate-limited so a
+/// dense frame doesn't produce an audio storm.
+pub struct Sonifier {
+    _stream: OutputStream,
+    handle: OutputStreamHandle,
+    last_played: Option<Instant>,
+}
+
+impl Sonifier {
+    /// Opens the default audio output, or `None` if no device is available.
+    pub fn try_new() -> Option<Self> {
+        let (stream, handle) = OutputStream::try_default().ok()?;
+        Some(Self {
+            _stream: stream,
+            handle,
+            last_played: None,
+        })
+    }
+
+    pub fn play(&mut self, cues: &[ToneCue]) {
+        let now = Instant::now();
+        if self
+            .last_played
+            .is_some_and(|last| now.duration_since(last) < SONIFY_RATE_LIMIT)
+        {
+            return;
+        }
+        self.last_played = Some(now);
+
+        for cue in cues {
+            let Ok(sink) = Sink::try_new(&self.handle) else {
+                continue;
+            };
+            sink.append(StereoTone::new(
+                cue.frequency_hz,
+                cue.gain_left,
+                cue.gain_right,
+            ));
+            sink.detach();
+        }
+    }
+}
+
+/// A short decaying sine burst panned across two interleaved channels.
+struct StereoTone {
+    frequency_hz: f32,
+    gain_left: f32,
+    gain_right: f32,
+    frame: u32,
+    total_frames: u32,
+    channel: u8,
+}
+
+impl StereoTone {
+    fn new(frequency_hz: f32, gain_left: f32, gain_right: f32) -> Self {
+        Self {
+            frequency_hz,
+            gain_left,
+            gain_right,
+            frame: 0,
+            total_frames: (SAMPLE_RATE as f32 * TONE_DURATION.as_secs_f32()) as u32,
+            channel: 0,
+        }
+    }
+}
+
+impl Iterator for StereoTone {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.frame >= self.total_frames {
+            return None;
+        }
+        let t = self.frame as f32 / SAMPLE_RATE as f32;
+        let envelope = 1.0 - (self.frame as f32 / self.total_frames as f32);
+        let carrier = (2.0 * PI * self.frequency_hz * t).sin() * envelope;
+        let sample = if self.channel == 0 {
+            carrier * self.gain_left
+        } else {
+            carrier * self.gain_right
+        };
+
+        if self.channel == 0 {
+            self.channel = 1;
+        } else {
+            self.channel = 0;
+            self.frame += 1;
+        }
+        Some(sample)
+    }
+}
+
+impl Source for StereoTone {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        2
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(TONE_DURATION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ahead_detection_pans_evenly() {
+        let cue = ToneCue::new(0.0, 30.0, 0.0);
+        assert!((cue.gain_left - cue.gain_right).abs() < 0.05);
+    }
+
+    #[test]
+    fn closing_doppler_raises_pitch() {
+        let closing = ToneCue::new(50.0, 20.0, 0.0);
+        let receding = ToneCue::new(-50.0, 20.0, 0.0);
+        assert!(closing.frequency_hz > receding.frequency_hz);
+    }
+
+    #[test]
+    fn stereo_tone_emits_interleaved_samples_for_its_duration() {
+        let tone = StereoTone::new(440.0, 1.0, 1.0);
+        let expected_frames = (SAMPLE_RATE as f32 * TONE_DURATION.as_secs_f32()) as u32;
+        assert_eq!(tone.count() as u32, expected_frames * 2);
+    }
+}
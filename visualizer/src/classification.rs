@@ -0,0 +1,132 @@
+use gmticore::agp_interface::DetectionRecord;
+use serde::{Deserialize, Serialize};
+
+/// One named detection class: an RGB swatch, optional SNR/Doppler gates used
+/// to classify incoming detections, and a visibility toggle for the canvas.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ClassificationRule {
+    pub name: String,
+    pub color: (u8, u8, u8),
+    pub snr_min: Option<f32>,
+    pub snr_max: Option<f32>,
+    pub doppler_min: Option<f32>,
+    pub doppler_max: Option<f32>,
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+impl ClassificationRule {
+    fn gates(&self, record: &DetectionRecord) -> bool {
+        if let Some(min) = self.snr_min {
+            if record.snr < min {
+                return false;
+            }
+        }
+        if let Some(max) = self.snr_max {
+            if record.snr > max {
+                return false;
+            }
+        }
+        if let Some(min) = self.doppler_min {
+            if record.doppler < min {
+                return false;
+            }
+        }
+        if let Some(max) = self.doppler_max {
+            if record.doppler > max {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn iced_color(&self) -> iced::Color {
+        iced::Color::from_rgb8(self.color.0, self.color.1, self.color.2)
+    }
+}
+
+/// Default three-class palette: fast closing movers as vehicles, near-zero
+/// Doppler returns as clutter, and everything else unclassified. The last
+/// class has no gates, so it always matches and nothing falls through.
+pub(crate) fn default_classes() -> Vec<ClassificationRule> {
+    vec![
+        ClassificationRule {
+            name: "Vehicle".into(),
+            color: (220, 70, 70),
+            snr_min: Some(12.0),
+            snr_max: None,
+            doppler_min: Some(3.0),
+            doppler_max: None,
+            visible: true,
+        },
+        ClassificationRule {
+            name: "Clutter".into(),
+            color: (120, 120, 120),
+            snr_min: None,
+            snr_max: None,
+            doppler_min: Some(-3.0),
+            doppler_max: Some(3.0),
+            visible: true,
+        },
+        ClassificationRule {
+            name: "Unknown".into(),
+            color: (80, 170, 220),
+            snr_min: None,
+            snr_max: None,
+            doppler_min: None,
+            doppler_max: None,
+            visible: true,
+        },
+    ]
+}
+
+/// Classifies `record` by the first rule (in definition order) whose gates
+/// match, regardless of visibility — callers hide the blip themselves if the
+/// matched rule is toggled off.
+pub(crate) fn classify<'a>(
+    record: &DetectionRecord,
+    classes: &'a [ClassificationRule],
+) -> Option<&'a ClassificationRule> {
+    classes.iter().find(|class| class.gates(record))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(doppler: f32, snr: f32) -> DetectionRecord {
+        DetectionRecord::new(0.0, 500.0, doppler, snr, 0.0, 0.0)
+    }
+
+    #[test]
+    fn fast_closing_high_snr_classifies_as_vehicle() {
+        let classes = default_classes();
+        let matched = classify(&record(20.0, 18.0), &classes).unwrap();
+        assert_eq!(matched.name, "Vehicle");
+    }
+
+    #[test]
+    fn near_zero_doppler_classifies_as_clutter() {
+        let classes = default_classes();
+        let matched = classify(&record(1.0, 5.0), &classes).unwrap();
+        assert_eq!(matched.name, "Clutter");
+    }
+
+    #[test]
+    fn everything_else_falls_back_to_unknown() {
+        let classes = default_classes();
+        let matched = classify(&record(-20.0, 5.0), &classes).unwrap();
+        assert_eq!(matched.name, "Unknown");
+    }
+
+    #[test]
+    fn first_matching_rule_wins_when_gates_overlap() {
+        let classes = default_classes();
+        let matched = classify(&record(5.0, 15.0), &classes).unwrap();
+        assert_eq!(matched.name, "Vehicle");
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::{HashMap, VecDeque};
+
+/// A point not yet visited by the DBSCAN sweep.
+const UNCLASSIFIED: i32 = -2;
+/// A point visited but without a dense-enough neighborhood to seed or join
+/// a cluster.
+pub(crate) const NOISE: i32 = -1;
+
+fn grid_cell(point: (f32, f32), eps: f32) -> (i32, i32) {
+    ((point.0 / eps).floor() as i32, (point.1 / eps).floor() as i32)
+}
+
+/// Buckets `points` into a uniform grid sized to `eps`, so an
+/// eps-neighborhood query only has to scan the cell a point falls in plus
+/// its eight neighbors instead of every other point.
+fn build_grid(points: &[(f32, f32)], eps: f32) -> HashMap<(i32, i32), Vec<usize>> {
+    let mut grid: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+    for (index, point) in points.iter().enumerate() {
+        grid.entry(grid_cell(*point, eps)).or_default().push(index);
+    }
+    grid
+}
+
+fn region_query(
+    points: &[(f32, f32)],
+    grid: &HashMap<(i32, i32), Vec<usize>>,
+    eps: f32,
+    index: usize,
+) -> Vec<usize> {
+    let (cell_x, cell_y) = grid_cell(points[index], eps);
+    let eps_sq = eps * eps;
+    let mut neighbors = Vec::new();
+    for dx in -1..=1 {
+        for dy in -1..=1 {
+            let Some(bucket) = grid.get(&(cell_x + dx, cell_y + dy)) else {
+                continue;
+            };
+            for &candidate in bucket {
+                let dx = points[index].0 - points[candidate].0;
+                let dy = points[index].1 - points[candidate].1;
+                if dx * dx + dy * dy <= eps_sq {
+                    neighbors.push(candidate);
+                }
+            }
+        }
+    }
+    neighbors
+}
+
+/// DBSCAN over `points`: a point is a core point if at least `min_pts`
+/// neighbors (including itself) lie within `eps`; clusters grow by
+/// density-reachability from core points, and anything left unreached is
+/// `NOISE`. Returns one label per input point (cluster ids starting at 0,
+/// or `NOISE`); with `eps <= 0` no clustering runs and every label is -2.
+/// Neighbor queries go through a uniform grid keyed by `eps`, so the pass
+/// stays near O(n) instead of the naive O(n^2).
+pub(crate) fn dbscan(points: &[(f32, f32)], eps: f32, min_pts: usize) -> Vec<i32> {
+    let n = points.len();
+    let mut labels = vec![UNCLASSIFIED; n];
+    if n == 0 || eps <= 0.0 {
+        return labels;
+    }
+    let grid = build_grid(points, eps);
+    let mut next_cluster_id = 0;
+
+    for index in 0..n {
+        if labels[index] != UNCLASSIFIED {
+            continue;
+        }
+        let neighbors = region_query(points, &grid, eps, index);
+        if neighbors.len() < min_pts {
+            labels[index] = NOISE;
+            continue;
+        }
+
+        let cluster_id = next_cluster_id;
+        next_cluster_id += 1;
+        labels[index] = cluster_id;
+
+        let mut seeds: VecDeque<usize> = neighbors.into_iter().collect();
+        while let Some(seed) = seeds.pop_front() {
+            if labels[seed] == NOISE {
+                labels[seed] = cluster_id;
+            }
+            if labels[seed] != UNCLASSIFIED {
+                continue;
+            }
+            labels[seed] = cluster_id;
+            let seed_neighbors = region_query(points, &grid, eps, seed);
+            if seed_neighbors.len() >= min_pts {
+                seeds.extend(seed_neighbors);
+            }
+        }
+    }
+
+    labels
+}
+
+fn cross(o: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+/// Convex hull of `points` via Andrew's monotone chain: sort by (x, y), then
+/// sweep lower and upper chains, popping any vertex that would make a
+/// clockwise (non-left) turn. Returns the hull vertices in counter-clockwise
+/// order; degenerate inputs (fewer than 3 distinct points) come back sorted
+/// and deduplicated.
+pub(crate) fn convex_hull(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut lower: Vec<(f32, f32)> = Vec::new();
+    for &point in &sorted {
+        while lower.len() >= 2 && cross(lower[lower.len() - 2], lower[lower.len() - 1], point) <= 0.0 {
+            lower.pop();
+        }
+        lower.push(point);
+    }
+
+    let mut upper: Vec<(f32, f32)> = Vec::new();
+    for &point in sorted.iter().rev() {
+        while upper.len() >= 2 && cross(upper[upper.len() - 2], upper[upper.len() - 1], point) <= 0.0 {
+            upper.pop();
+        }
+        upper.push(point);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense_cluster_forms_a_single_cluster() {
+        let points = vec![(0.0, 0.0), (0.01, 0.0), (0.0, 0.01), (0.01, 0.01), (5.0, 5.0)];
+        let labels = dbscan(&points, 0.05, 3);
+        assert_eq!(labels[0], labels[1]);
+        assert_eq!(labels[1], labels[2]);
+        assert_eq!(labels[2], labels[3]);
+        assert_ne!(labels[0], NOISE);
+        assert_eq!(labels[4], NOISE);
+    }
+
+    #[test]
+    fn sparse_points_are_all_noise() {
+        let points = vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)];
+        let labels = dbscan(&points, 0.1, 2);
+        assert!(labels.iter().all(|&label| label == NOISE));
+    }
+
+    #[test]
+    fn convex_hull_of_a_square_returns_its_corners() {
+        let points = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.5, 0.5)];
+        let hull = convex_hull(&points);
+        assert_eq!(hull.len(), 4);
+        assert!(!hull.contains(&(0.5, 0.5)));
+    }
+
+    #[test]
+    fn convex_hull_of_two_points_returns_them_unchanged() {
+        let points = vec![(0.0, 0.0), (1.0, 1.0)];
+        assert_eq!(convex_hull(&points), points);
+    }
+}
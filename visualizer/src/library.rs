@@ -0,0 +1,216 @@
+use crate::classification::ClassificationRule;
+use crate::ScenarioConfig;
+use anyhow::{Context, Result};
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::params;
+
+/// One row of the operator's saved-scenario catalog: a name, free-form tags,
+/// the `ScenarioConfig` it reposts, and the classification palette it was
+/// rendered with, so reloading a scenario always looks the same.
+#[derive(Debug, Clone)]
+pub(crate) struct SavedScenario {
+    pub id: i64,
+    pub name: String,
+    pub tags: Vec<String>,
+    pub config: ScenarioConfig,
+    pub classes: Vec<ClassificationRule>,
+}
+
+/// SQLite-backed catalog of saved `ScenarioConfig` presets, so operators can
+/// reload a clutter/SNR/platform setup instead of retyping sixteen fields.
+pub(crate) struct ScenarioLibrary {
+    pool: Pool<SqliteConnectionManager>,
+}
+
+impl ScenarioLibrary {
+    pub fn open(path: &str) -> Result<Self> {
+        let pool = Pool::new(SqliteConnectionManager::file(path))
+            .context("building scenario library pool")?;
+        Self::from_pool(pool)
+    }
+
+    /// An in-memory library capped at one pooled connection, since a fresh
+    /// connection to `:memory:` would otherwise hand back an empty database.
+    pub fn in_memory() -> Result<Self> {
+        let pool = Pool::builder()
+            .max_size(1)
+            .build(SqliteConnectionManager::memory())
+            .context("building in-memory scenario library pool")?;
+        Self::from_pool(pool)
+    }
+
+    fn from_pool(pool: Pool<SqliteConnectionManager>) -> Result<Self> {
+        pool.get()
+            .context("getting scenario library connection")?
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS scenarios (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    name TEXT NOT NULL,
+                    tags TEXT NOT NULL,
+                    config_json TEXT NOT NULL,
+                    classes_json TEXT NOT NULL DEFAULT '[]'
+                );",
+            )
+            .context("creating scenarios table")?;
+        Ok(Self { pool })
+    }
+
+    pub fn save(
+        &self,
+        name: &str,
+        tags: &[String],
+        config: &ScenarioConfig,
+        classes: &[ClassificationRule],
+    ) -> Result<i64> {
+        let conn = self
+            .pool
+            .get()
+            .context("getting scenario library connection")?;
+        let config_json = serde_json::to_string(config).context("serializing scenario config")?;
+        let classes_json =
+            serde_json::to_string(classes).context("serializing classification rules")?;
+        conn.execute(
+            "INSERT INTO scenarios (name, tags, config_json, classes_json) VALUES (?1, ?2, ?3, ?4)",
+            params![name, tags.join(","), config_json, classes_json],
+        )
+        .context("inserting saved scenario")?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Lists saved scenarios, newest first, optionally restricted to those
+    /// whose name or tags contain `filter` (case-insensitive).
+    pub fn list(&self, filter: Option<&str>) -> Result<Vec<SavedScenario>> {
+        let conn = self
+            .pool
+            .get()
+            .context("getting scenario library connection")?;
+        let mut stmt = conn
+            .prepare("SELECT id, name, tags, config_json, classes_json FROM scenarios ORDER BY id DESC")
+            .context("preparing scenario list query")?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            })
+            .context("querying saved scenarios")?;
+
+        let needle = filter.map(|f| f.to_lowercase());
+        let mut scenarios = Vec::new();
+        for row in rows {
+            let (id, name, tags, config_json, classes_json) =
+                row.context("reading saved scenario row")?;
+            let tag_list: Vec<String> = tags
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect();
+            if let Some(needle) = needle.as_ref() {
+                let matches = name.to_lowercase().contains(needle)
+                    || tag_list.iter().any(|t| t.to_lowercase().contains(needle));
+                if !matches {
+                    continue;
+                }
+            }
+            let config: ScenarioConfig = serde_json::from_str(&config_json)
+                .context("deserializing saved scenario config")?;
+            let classes: Vec<ClassificationRule> = serde_json::from_str(&classes_json)
+                .context("deserializing saved classification rules")?;
+            scenarios.push(SavedScenario {
+                id,
+                name,
+                tags: tag_list,
+                config,
+                classes,
+            });
+        }
+        Ok(scenarios)
+    }
+
+    pub fn get(&self, id: i64) -> Result<Option<SavedScenario>> {
+        Ok(self.list(None)?.into_iter().find(|saved| saved.id == id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classification::default_classes;
+
+    fn sample_config() -> ScenarioConfig {
+        ScenarioConfig {
+            taps: Some(4),
+            range_bins: Some(2048),
+            doppler_bins: Some(256),
+            frequency: Some(1_050_000_000.0),
+            noise: Some(0.07),
+            seed: Some(312),
+            description: None,
+            scenario: Some("Airborne sweep".into()),
+            platform_type: Some("Airborne ISR".into()),
+            platform_velocity_kmh: Some(750.0),
+            altitude_m: Some(8200.0),
+            area_width_km: Some(10.0),
+            area_height_km: Some(10.0),
+            clutter_level: Some(0.45),
+            snr_target_db: Some(18.0),
+            interference_db: Some(-10.0),
+            target_motion: None,
+            timestamp_start: None,
+        }
+    }
+
+    #[test]
+    fn saves_and_recalls_a_scenario() {
+        let library = ScenarioLibrary::in_memory().unwrap();
+        let id = library
+            .save(
+                "Airborne sweep",
+                &["clutter".into(), "default".into()],
+                &sample_config(),
+                &default_classes(),
+            )
+            .unwrap();
+
+        let loaded = library.get(id).unwrap().unwrap();
+        assert_eq!(loaded.name, "Airborne sweep");
+        assert_eq!(loaded.tags, vec!["clutter", "default"]);
+        assert_eq!(loaded.classes.len(), default_classes().len());
+    }
+
+    #[test]
+    fn list_filters_by_name_or_tag() {
+        let library = ScenarioLibrary::in_memory().unwrap();
+        library
+            .save(
+                "Airborne sweep",
+                &["clutter".into()],
+                &sample_config(),
+                &default_classes(),
+            )
+            .unwrap();
+        library
+            .save(
+                "Maritime patrol",
+                &["low-snr".into()],
+                &sample_config(),
+                &default_classes(),
+            )
+            .unwrap();
+
+        let filtered = library.list(Some("clutter")).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "Airborne sweep");
+    }
+
+    #[test]
+    fn get_returns_none_for_unknown_id() {
+        let library = ScenarioLibrary::in_memory().unwrap();
+        assert!(library.get(999).unwrap().is_none());
+    }
+}
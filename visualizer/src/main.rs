@@ -1,4 +1,21 @@
+mod alerts;
+mod audio;
+mod classification;
+mod clustering;
+mod library;
+mod persistence;
+mod speech;
+mod stream;
+mod tracking;
+
+use alerts::{ActiveAlert, AlertRule, AlertRules, AlertSeverity};
+use audio::{Sonifier, ToneCue};
+use classification::ClassificationRule;
+use persistence::PersistenceBuffer;
+use tracking::{RenderedTrack, Tracker};
+use library::{SavedScenario, ScenarioLibrary};
 use gmticore::agp_interface::{DetectionRecord, ScenarioMetadata};
+use gmticore::math::Angle;
 use iced::{
     mouse, time,
     widget::{
@@ -6,10 +23,13 @@ use iced::{
         canvas::{self, Canvas, Frame, Geometry, Path, Stroke},
         column, row, scrollable, slider, text, text_input, Column, Container,
     },
-    Alignment, Color, Element, Length, Point, Rectangle, Renderer, Subscription, Task, Theme,
+    Alignment, Color, Element, Length, Pixels, Point, Rectangle, Renderer, Size, Subscription,
+    Task, Theme,
 };
 use serde::{Deserialize, Serialize};
+use speech::Speaker;
 use std::{
+    collections::{HashSet, VecDeque},
     f32::consts::PI,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
@@ -27,7 +47,11 @@ fn application_title(_: &Visualizer) -> String {
 }
 
 fn application_subscription(_: &Visualizer) -> Subscription<Message> {
-    time::every(Duration::from_secs(1)).map(|_| Message::Tick)
+    Subscription::batch(vec![
+        time::every(Duration::from_secs(1)).map(|_| Message::Tick),
+        time::every(Duration::from_millis(SWEEP_TICK_MILLIS)).map(|_| Message::SweepTick),
+        stream::connect(),
+    ])
 }
 
 fn application_theme(_: &Visualizer) -> Theme {
@@ -35,6 +59,14 @@ fn application_theme(_: &Visualizer) -> Theme {
 }
 
 const STREAM_DURATION_SECS: u32 = 600;
+/// Local SQLite file backing the saved-scenario catalog, next to the binary.
+const SCENARIO_LIBRARY_PATH: &str = "scenario_library.sqlite3";
+/// How long a streamed detection lingers in the PPI persistence trail before
+/// it's dropped.
+const PERSISTENCE_WINDOW_SECS: f64 = 8.0;
+/// Animation cadence for the PPI sweep beam and persistence fade, decoupled
+/// from the once-a-second telemetry poll in `Message::Tick`.
+const SWEEP_TICK_MILLIS: u64 = 50;
 
 #[derive(Debug, Clone, Copy)]
 struct StreamSession {
@@ -43,7 +75,6 @@ struct StreamSession {
     start_timestamp: f64,
 }
 
-#[derive(Debug)]
 struct Visualizer {
     config: ConfigForm,
     payload: Option<VisualizationPayload>,
@@ -52,10 +83,42 @@ struct Visualizer {
     history: Vec<String>,
     view_state: DetectionViewState,
     stream_session: Option<StreamSession>,
+    sonifier: Option<Sonifier>,
+    sonification_enabled: bool,
+    speaker: Option<Speaker>,
+    speech_enabled: bool,
+    speech_verbosity: SpeechVerbosity,
+    announced_detections: HashSet<u64>,
+    /// Falls back to HTTP polling in `Message::Tick` while this is false.
+    /// Any `PayloadFetched` error clears it and any received payload sets it,
+    /// whether that payload came over the `/stream` websocket or from a poll.
+    stream_healthy: bool,
+    library: ScenarioLibrary,
+    scenarios: Vec<SavedScenario>,
+    scenario_filter: String,
+    scenario_tags_input: String,
+    alert_rule_form: AlertRuleForm,
+    /// Acknowledged alerts stay in place (so the timeline doesn't jump) but
+    /// stop flagging their blip on `DetectionMap`.
+    active_alerts: VecDeque<ActiveAlert>,
+    next_alert_id: u64,
+    class_forms: Vec<ClassFormRow>,
+    tracker: Tracker,
+    cluster_form: ClusterForm,
+    live_mode: bool,
+    persistence: PersistenceBuffer,
+    sweep_angle_deg: f32,
+    sweep_form: SweepForm,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpeechVerbosity {
+    SummaryOnly,
+    PerDetection,
 }
 
 #[derive(Debug, Clone)]
-enum Message {
+pub(crate) enum Message {
     Tick,
     PayloadFetched(Result<VisualizationPayload, String>),
     ConfigFieldChanged(ConfigField, String),
@@ -69,6 +132,56 @@ enum Message {
     ResetView,
     StartRun,
     StopRun,
+    ToggleSonification,
+    ToggleSpeech,
+    ToggleSpeechVerbosity,
+    ScenarioTagsChanged(String),
+    SaveScenario,
+    LoadScenario(i64),
+    FilterScenarios(String),
+    AlertRuleFieldChanged(AlertRuleField, String),
+    AlertRaised {
+        detection_index: usize,
+        rule: AlertRule,
+        severity: AlertSeverity,
+    },
+    AcknowledgeAlert(u64),
+    ClassFieldChanged(usize, ClassField, String),
+    ToggleClass(String),
+    ClusterFieldChanged(ClusterField, String),
+    ToggleClustering,
+    ToggleLiveMode,
+    SweepFieldChanged(SweepField, String),
+    SweepTick,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum AlertRuleField {
+    MinSnr,
+    MaxRange,
+    ClosingDoppler,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ClassField {
+    ColorR,
+    ColorG,
+    ColorB,
+    SnrMin,
+    SnrMax,
+    DopplerMin,
+    DopplerMax,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ClusterField {
+    Eps,
+    MinPts,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum SweepField {
+    RateDegPerSec,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -94,6 +207,9 @@ enum ConfigField {
 
 impl Visualizer {
     fn boot() -> (Self, Task<Message>) {
+        let library = ScenarioLibrary::open(SCENARIO_LIBRARY_PATH)
+            .expect("failed to open scenario library");
+        let scenarios = library.list(None).unwrap_or_default();
         (
             Visualizer {
                 config: ConfigForm::default(),
@@ -103,6 +219,30 @@ impl Visualizer {
                 history: Vec::new(),
                 view_state: DetectionViewState::default(),
                 stream_session: None,
+                sonifier: Sonifier::try_new(),
+                sonification_enabled: false,
+                speaker: Speaker::try_new(),
+                speech_enabled: false,
+                speech_verbosity: SpeechVerbosity::SummaryOnly,
+                announced_detections: HashSet::new(),
+                stream_healthy: true,
+                library,
+                scenarios,
+                scenario_filter: String::new(),
+                scenario_tags_input: String::new(),
+                alert_rule_form: AlertRuleForm::default(),
+                active_alerts: VecDeque::new(),
+                next_alert_id: 0,
+                class_forms: classification::default_classes()
+                    .iter()
+                    .map(ClassFormRow::from_rule)
+                    .collect(),
+                tracker: Tracker::new(),
+                cluster_form: ClusterForm::default(),
+                live_mode: false,
+                persistence: PersistenceBuffer::new(PERSISTENCE_WINDOW_SECS),
+                sweep_angle_deg: 0.0,
+                sweep_form: SweepForm::default(),
             },
             Task::perform(fetch_payload(), Message::PayloadFetched),
         )
@@ -111,7 +251,13 @@ impl Visualizer {
     fn update(state: &mut Self, message: Message) -> Task<Message> {
         match message {
             Message::Tick => {
-                let fetch_task = Task::perform(fetch_payload(), Message::PayloadFetched);
+                // The `/stream` websocket pushes payloads as they're produced;
+                // this fallback poll only runs while that socket is down.
+                let fetch_task = if state.stream_healthy {
+                    Task::none()
+                } else {
+                    Task::perform(fetch_payload(), Message::PayloadFetched)
+                };
                 if let Some(session) = state.stream_session.as_mut() {
                     if session.remaining_secs == 0 {
                         state.stream_session = None;
@@ -136,7 +282,48 @@ impl Visualizer {
                 fetch_task
             }
             Message::PayloadFetched(Ok(payload)) => {
+                state.stream_healthy = true;
                 state.waveform = payload.power_profile.clone();
+                if state.sonification_enabled {
+                    if let Some(sonifier) = state.sonifier.as_mut() {
+                        let cues = sonification_cues(
+                            &payload.detection_records,
+                            state.view_state.mode,
+                            payload.scenario_metadata.as_ref(),
+                        );
+                        sonifier.play(&cues);
+                    }
+                }
+                if state.speech_enabled {
+                    let current_ids: HashSet<u64> = payload
+                        .detection_records
+                        .iter()
+                        .map(|record| record.timestamp.to_bits())
+                        .collect();
+                    let new_detections: Vec<&DetectionRecord> = payload
+                        .detection_records
+                        .iter()
+                        .filter(|record| !state.announced_detections.contains(&record.timestamp.to_bits()))
+                        .collect();
+                    if let Some(speaker) = state.speaker.as_mut() {
+                        speaker.speak(&speech_summary(&payload.detection_records));
+                        if state.speech_verbosity == SpeechVerbosity::PerDetection {
+                            for record in &new_detections {
+                                speaker.speak(&detection_announcement(record));
+                            }
+                        }
+                    }
+                    state.announced_detections = current_ids;
+                }
+                let raised_alerts = alerts::evaluate(&payload.detection_records, &state.alert_rule_form.to_rules());
+                let track_timestamp = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_else(|_| Duration::ZERO)
+                    .as_secs_f64();
+                state.tracker.update(&payload.detection_records, track_timestamp);
+                state
+                    .persistence
+                    .push(payload.detection_records.clone(), track_timestamp);
                 state.payload = Some(payload.clone());
                 state.status = format!(
                     "Telemetry received: {} detections / {} bins",
@@ -148,9 +335,20 @@ impl Visualizer {
                     payload.detection_count,
                     payload.power_profile.len()
                 ));
-                Task::none()
+                Task::batch(
+                    raised_alerts
+                        .into_iter()
+                        .map(|raised| {
+                            Task::done(Message::AlertRaised {
+                                detection_index: raised.detection_index,
+                                rule: raised.rule,
+                                severity: raised.severity,
+                            })
+                        }),
+                )
             }
             Message::PayloadFetched(Err(err)) => {
+                state.stream_healthy = false;
                 state.status = format!("Telemetry error: {err}");
                 Task::none()
             }
@@ -167,6 +365,11 @@ impl Visualizer {
                     state.status = message;
                 }
                 state.push_history("Scenario submitted".into());
+                if state.speech_enabled {
+                    if let Some(speaker) = state.speaker.as_mut() {
+                        speaker.speak("Scenario submitted");
+                    }
+                }
                 Task::none()
             }
             Message::ConfigSubmitted(Err(err)) => {
@@ -208,11 +411,138 @@ impl Visualizer {
                     start_timestamp,
                 });
                 state.status = format!("Streaming run: {}s remaining", STREAM_DURATION_SECS);
+                if state.speech_enabled {
+                    if let Some(speaker) = state.speaker.as_mut() {
+                        speaker.speak("Run started");
+                    }
+                }
                 Task::none()
             }
             Message::StopRun => {
                 state.stream_session = None;
                 state.status = "Streaming run stopped".into();
+                if state.speech_enabled {
+                    if let Some(speaker) = state.speaker.as_mut() {
+                        speaker.speak("Run stopped");
+                    }
+                }
+                Task::none()
+            }
+            Message::ToggleSonification => {
+                state.sonification_enabled = !state.sonification_enabled;
+                if state.sonification_enabled && state.sonifier.is_none() {
+                    state.status = "Sonification enabled, but no audio output was found".into();
+                }
+                Task::none()
+            }
+            Message::ToggleSpeech => {
+                state.speech_enabled = !state.speech_enabled;
+                if state.speech_enabled && state.speaker.is_none() {
+                    state.status = "Speech enabled, but no speech backend was found".into();
+                }
+                Task::none()
+            }
+            Message::ToggleSpeechVerbosity => {
+                state.speech_verbosity = match state.speech_verbosity {
+                    SpeechVerbosity::SummaryOnly => SpeechVerbosity::PerDetection,
+                    SpeechVerbosity::PerDetection => SpeechVerbosity::SummaryOnly,
+                };
+                Task::none()
+            }
+            Message::ScenarioTagsChanged(value) => {
+                state.scenario_tags_input = value;
+                Task::none()
+            }
+            Message::SaveScenario => {
+                let tags: Vec<String> = state
+                    .scenario_tags_input
+                    .split(',')
+                    .map(|tag| tag.trim().to_string())
+                    .filter(|tag| !tag.is_empty())
+                    .collect();
+                let config = state.config.to_payload();
+                let name = state.config.scenario_name.clone();
+                let classes = state.classes();
+                match state.library.save(&name, &tags, &config, &classes) {
+                    Ok(_) => {
+                        state.status = format!("Scenario '{name}' saved");
+                        state.refresh_scenarios();
+                    }
+                    Err(err) => state.status = format!("Save failed: {err}"),
+                }
+                Task::none()
+            }
+            Message::LoadScenario(id) => {
+                match state.library.get(id) {
+                    Ok(Some(saved)) => {
+                        state.config.load_payload(&saved.config);
+                        state.scenario_tags_input = saved.tags.join(", ");
+                        state.class_forms = saved.classes.iter().map(ClassFormRow::from_rule).collect();
+                        state.status = format!("Loaded scenario '{}'", saved.name);
+                    }
+                    Ok(None) => state.status = "Scenario not found".into(),
+                    Err(err) => state.status = format!("Load failed: {err}"),
+                }
+                Task::none()
+            }
+            Message::FilterScenarios(value) => {
+                state.scenario_filter = value;
+                state.refresh_scenarios();
+                Task::none()
+            }
+            Message::AlertRuleFieldChanged(field, value) => {
+                state.alert_rule_form.update_field(field, value);
+                Task::none()
+            }
+            Message::AlertRaised {
+                detection_index,
+                rule,
+                severity,
+            } => {
+                state.raise_alert(detection_index, rule, severity);
+                Task::none()
+            }
+            Message::AcknowledgeAlert(id) => {
+                if let Some(alert) = state.active_alerts.iter_mut().find(|alert| alert.id == id) {
+                    alert.acknowledged = true;
+                }
+                Task::none()
+            }
+            Message::ClassFieldChanged(index, field, value) => {
+                if let Some(row) = state.class_forms.get_mut(index) {
+                    row.update_field(field, value);
+                }
+                Task::none()
+            }
+            Message::ToggleClass(name) => {
+                if let Some(row) = state.class_forms.iter_mut().find(|row| row.name == name) {
+                    row.visible = !row.visible;
+                }
+                Task::none()
+            }
+            Message::ClusterFieldChanged(field, value) => {
+                state.cluster_form.update_field(field, value);
+                Task::none()
+            }
+            Message::ToggleClustering => {
+                state.cluster_form.enabled = !state.cluster_form.enabled;
+                Task::none()
+            }
+            Message::ToggleLiveMode => {
+                state.live_mode = !state.live_mode;
+                Task::none()
+            }
+            Message::SweepFieldChanged(field, value) => {
+                state.sweep_form.update_field(field, value);
+                Task::none()
+            }
+            Message::SweepTick => {
+                if state.live_mode {
+                    let dt_secs = SWEEP_TICK_MILLIS as f32 / 1000.0;
+                    state.sweep_angle_deg =
+                        (state.sweep_angle_deg + state.sweep_form.to_rate_deg_per_sec() * dt_secs)
+                            % 360.0;
+                }
                 Task::none()
             }
         }
@@ -286,9 +616,18 @@ impl Visualizer {
             text_input("Target motion summary", &state.config.target_motion)
                 .on_input(|value| Message::ConfigFieldChanged(ConfigField::TargetMotion, value))
                 .padding(6),
-            button("POST scenario")
-                .on_press(Message::SubmitConfig)
-                .padding(10),
+            text_input("Tags (comma-separated)", &state.scenario_tags_input)
+                .on_input(Message::ScenarioTagsChanged)
+                .padding(6),
+            row![
+                button("POST scenario")
+                    .on_press(Message::SubmitConfig)
+                    .padding(10),
+                button("Save scenario")
+                    .on_press(Message::SaveScenario)
+                    .padding(10),
+            ]
+            .spacing(10),
             text(&state.status).size(14),
             column![
                 text("Parameter definitions").size(16),
@@ -310,6 +649,46 @@ impl Visualizer {
             ]
             .spacing(4)
             .padding(6),
+            text("Alert rules").size(16),
+            text_input("Min SNR (dB)", &state.alert_rule_form.min_snr)
+                .on_input(|value| Message::AlertRuleFieldChanged(AlertRuleField::MinSnr, value))
+                .padding(6),
+            text_input("Keep-out max range (m)", &state.alert_rule_form.max_range)
+                .on_input(|value| Message::AlertRuleFieldChanged(AlertRuleField::MaxRange, value))
+                .padding(6),
+            text_input(
+                "Closing Doppler (m/s)",
+                &state.alert_rule_form.closing_doppler
+            )
+            .on_input(|value| {
+                Message::AlertRuleFieldChanged(AlertRuleField::ClosingDoppler, value)
+            })
+            .padding(6),
+            text("Classification layers").size(16),
+            class_edit_rows(&state.class_forms),
+            text("Clustering").size(16),
+            row![
+                button(if state.cluster_form.enabled {
+                    "Disable clustering"
+                } else {
+                    "Enable clustering"
+                })
+                .on_press(Message::ToggleClustering)
+                .padding(4),
+                text_input("eps", &state.cluster_form.eps)
+                    .on_input(|value| Message::ClusterFieldChanged(ClusterField::Eps, value))
+                    .width(Length::Fixed(64.0)),
+                text_input("min pts", &state.cluster_form.min_pts)
+                    .on_input(|value| Message::ClusterFieldChanged(ClusterField::MinPts, value))
+                    .width(Length::Fixed(64.0)),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
+            text("Scenario library").size(16),
+            text_input("Filter by name or tag", &state.scenario_filter)
+                .on_input(Message::FilterScenarios)
+                .padding(6),
+            scenario_library_list(&state.scenarios),
         ]
         .spacing(10)
         .padding(16)
@@ -350,6 +729,31 @@ impl Visualizer {
         .width(Length::Fill)
         .height(Length::Fixed(260.0));
 
+        let range_doppler_map = Canvas::new(RangeDopplerMap {
+            matrix: state
+                .payload
+                .as_ref()
+                .map(|payload| payload.range_doppler_matrix.clone())
+                .unwrap_or_default(),
+            range_bins: state
+                .payload
+                .as_ref()
+                .map(|payload| payload.range_doppler_range_bins)
+                .unwrap_or(0),
+            doppler_bins: state
+                .payload
+                .as_ref()
+                .map(|payload| payload.range_doppler_doppler_bins)
+                .unwrap_or(0),
+            records: detection_records.clone(),
+            metadata: state
+                .payload
+                .as_ref()
+                .and_then(|payload| payload.scenario_metadata.clone()),
+        })
+        .width(Length::Fill)
+        .height(Length::Fixed(260.0));
+
         let scenario_metadata = state
             .payload
             .as_ref()
@@ -405,21 +809,101 @@ impl Visualizer {
                 .on_press(Message::ToggleLabels)
                 .padding(4),
                 button("Reset view").on_press(Message::ResetView).padding(4),
+                button(if state.sonification_enabled {
+                    "Disable audio"
+                } else {
+                    "Enable audio"
+                })
+                .on_press(Message::ToggleSonification)
+                .padding(4),
+                button(if state.speech_enabled {
+                    "Disable speech"
+                } else {
+                    "Enable speech"
+                })
+                .on_press(Message::ToggleSpeech)
+                .padding(4),
+                button(if state.speech_verbosity == SpeechVerbosity::PerDetection {
+                    "Speech: per-detection"
+                } else {
+                    "Speech: summary only"
+                })
+                .on_press(Message::ToggleSpeechVerbosity)
+                .padding(4),
+                button(if state.live_mode {
+                    "Disable live PPI"
+                } else {
+                    "Enable live PPI"
+                })
+                .on_press(Message::ToggleLiveMode)
+                .padding(4),
             ]
             .spacing(12),
+            row![
+                text("Sweep rate (deg/s)").size(12),
+                text_input("90", &state.sweep_form.rate_deg_per_sec)
+                    .on_input(|value| {
+                        Message::SweepFieldChanged(SweepField::RateDegPerSec, value)
+                    })
+                    .width(Length::Fixed(64.0)),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center),
         ]
         .spacing(6)
         .padding(6)
         .width(Length::Fill);
 
+        let alerted_indices: HashSet<usize> = state
+            .active_alerts
+            .iter()
+            .filter(|alert| !alert.acknowledged)
+            .map(|alert| alert.detection_index)
+            .collect();
+
+        let live_sweep = state.live_mode.then(|| {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_else(|_| Duration::ZERO)
+                .as_secs_f64();
+            LiveSweepOverlay {
+                sweep_angle_deg: state.sweep_angle_deg,
+                aged_detections: state.persistence.aged_detections(now),
+                window_secs: state.persistence.window_secs() as f32,
+            }
+        });
+
         let detection_canvas = Canvas::new(DetectionMap::new(
             &detection_records,
             state.view_state,
             scenario_metadata.clone(),
+            alerted_indices,
+            state.classes(),
+            state.tracker.rendered_tracks(),
+            state.cluster_form.to_params(),
+            live_sweep,
         ))
         .width(Length::Fill)
         .height(Length::Fixed(520.0));
 
+        let class_legend = row(state.class_forms.iter().map(|class_row| {
+            let swatch = Color::from_rgb8(
+                class_row.color_r.parse().unwrap_or(200),
+                class_row.color_g.parse().unwrap_or(200),
+                class_row.color_b.parse().unwrap_or(200),
+            );
+            row![
+                text(format!("\u{25CF} {}", class_row.name)).size(12).color(swatch),
+                button(if class_row.visible { "Hide" } else { "Show" })
+                    .on_press(Message::ToggleClass(class_row.name.clone()))
+                    .padding(2),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center)
+            .into()
+        }))
+        .spacing(14);
+
         let tag_row = if let Some(metadata) = scenario_metadata.as_ref() {
             row![
                 text(format!("Platform: {}", metadata.platform_type)).size(12),
@@ -547,14 +1031,20 @@ impl Visualizer {
             stream_controls,
             text("Power profile").size(18),
             waveform,
+            text("Range-Doppler heatmap").size(18),
+            range_doppler_map,
             detection_controls,
             text("Detection environment").size(18),
             detection_canvas,
+            class_legend,
             tag_row,
             axis_hint,
             metadata_panel,
             text("Recent detections").size(16),
             Container::new(detection_entries).padding(6),
+            text("Alerts").size(16),
+            Container::new(scrollable(alert_panel_list(&state.active_alerts)).height(Length::Fixed(120.0)))
+                .padding(6),
             text("Processing notes").size(16),
             Container::new(scrollable(notes_list).height(Length::Fixed(120.0))).padding(6),
             text("Activity log").size(16),
@@ -582,6 +1072,40 @@ impl Visualizer {
             self.history.remove(0);
         }
     }
+
+    fn refresh_scenarios(&mut self) {
+        let filter = self.scenario_filter.trim();
+        let filter = if filter.is_empty() { None } else { Some(filter) };
+        self.scenarios = self.library.list(filter).unwrap_or_default();
+    }
+
+    /// Logs a triggered rule as a timestamped, unacknowledged `ActiveAlert`,
+    /// dropping the oldest entry once the log exceeds `MAX_ACTIVE_ALERTS`.
+    fn raise_alert(&mut self, detection_index: usize, rule: AlertRule, severity: AlertSeverity) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_else(|_| Duration::ZERO)
+            .as_secs_f64();
+        let id = self.next_alert_id;
+        self.next_alert_id += 1;
+        self.active_alerts.push_back(ActiveAlert {
+            id,
+            detection_index,
+            rule,
+            severity,
+            timestamp,
+            acknowledged: false,
+        });
+        if self.active_alerts.len() > alerts::MAX_ACTIVE_ALERTS {
+            self.active_alerts.pop_front();
+        }
+    }
+
+    /// Parses the editable class-form rows into typed rules, for rendering
+    /// and for persisting alongside a saved scenario.
+    fn classes(&self) -> Vec<ClassificationRule> {
+        self.class_forms.iter().map(ClassFormRow::to_rule).collect()
+    }
 }
 
 async fn fetch_payload() -> Result<VisualizationPayload, String> {
@@ -721,10 +1245,196 @@ impl ConfigForm {
         payload.timestamp_start = timestamp;
         payload
     }
+
+    /// Repopulates every field from a previously-saved `ScenarioConfig`, the
+    /// inverse of `to_payload`, so `Message::LoadScenario` can refill the form.
+    fn load_payload(&mut self, config: &ScenarioConfig) {
+        self.taps = config.taps.map(|v| v.to_string()).unwrap_or_default();
+        self.range_bins = config.range_bins.map(|v| v.to_string()).unwrap_or_default();
+        self.doppler_bins = config.doppler_bins.map(|v| v.to_string()).unwrap_or_default();
+        self.frequency = config.frequency.map(|v| v.to_string()).unwrap_or_default();
+        self.noise = config.noise.map(|v| v.to_string()).unwrap_or_default();
+        self.seed = config.seed.map(|v| v.to_string()).unwrap_or_default();
+        self.description = config.description.clone().unwrap_or_default();
+        self.scenario_name = config.scenario.clone().unwrap_or_default();
+        self.platform_type = config.platform_type.clone().unwrap_or_default();
+        self.platform_velocity = config
+            .platform_velocity_kmh
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+        self.altitude = config.altitude_m.map(|v| v.to_string()).unwrap_or_default();
+        self.area_width = config.area_width_km.map(|v| v.to_string()).unwrap_or_default();
+        self.area_height = config.area_height_km.map(|v| v.to_string()).unwrap_or_default();
+        self.clutter_level = config.clutter_level.map(|v| v.to_string()).unwrap_or_default();
+        self.snr_target = config.snr_target_db.map(|v| v.to_string()).unwrap_or_default();
+        self.interference_level = config
+            .interference_db
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+        self.target_motion = config.target_motion.clone().unwrap_or_default();
+    }
+}
+
+/// Text-input-backed form for the operator-defined alert thresholds; parses
+/// into `alerts::AlertRules`, the same string-form-to-typed-payload split
+/// `ConfigForm` uses for `ScenarioConfig`.
+#[derive(Debug, Clone, Default)]
+struct AlertRuleForm {
+    min_snr: String,
+    max_range: String,
+    closing_doppler: String,
+}
+
+impl AlertRuleForm {
+    fn update_field(&mut self, field: AlertRuleField, value: String) {
+        match field {
+            AlertRuleField::MinSnr => self.min_snr = value,
+            AlertRuleField::MaxRange => self.max_range = value,
+            AlertRuleField::ClosingDoppler => self.closing_doppler = value,
+        }
+    }
+
+    fn to_rules(&self) -> AlertRules {
+        AlertRules {
+            min_snr_db: self.min_snr.parse().ok(),
+            max_range_m: self.max_range.parse().ok(),
+            closing_doppler_mps: self.closing_doppler.parse().ok(),
+        }
+    }
+}
+
+/// Text-input-backed form for one `ClassificationRule`: its name and
+/// visibility are edited directly, its color swatch and gates as strings
+/// parsed back into typed values by `to_rule`, mirroring `AlertRuleForm`.
+#[derive(Debug, Clone)]
+struct ClassFormRow {
+    name: String,
+    visible: bool,
+    color_r: String,
+    color_g: String,
+    color_b: String,
+    snr_min: String,
+    snr_max: String,
+    doppler_min: String,
+    doppler_max: String,
+}
+
+impl ClassFormRow {
+    fn from_rule(rule: &ClassificationRule) -> Self {
+        Self {
+            name: rule.name.clone(),
+            visible: rule.visible,
+            color_r: rule.color.0.to_string(),
+            color_g: rule.color.1.to_string(),
+            color_b: rule.color.2.to_string(),
+            snr_min: rule.snr_min.map(|v| v.to_string()).unwrap_or_default(),
+            snr_max: rule.snr_max.map(|v| v.to_string()).unwrap_or_default(),
+            doppler_min: rule.doppler_min.map(|v| v.to_string()).unwrap_or_default(),
+            doppler_max: rule.doppler_max.map(|v| v.to_string()).unwrap_or_default(),
+        }
+    }
+
+    fn update_field(&mut self, field: ClassField, value: String) {
+        match field {
+            ClassField::ColorR => self.color_r = value,
+            ClassField::ColorG => self.color_g = value,
+            ClassField::ColorB => self.color_b = value,
+            ClassField::SnrMin => self.snr_min = value,
+            ClassField::SnrMax => self.snr_max = value,
+            ClassField::DopplerMin => self.doppler_min = value,
+            ClassField::DopplerMax => self.doppler_max = value,
+        }
+    }
+
+    fn to_rule(&self) -> ClassificationRule {
+        ClassificationRule {
+            name: self.name.clone(),
+            color: (
+                self.color_r.parse().unwrap_or(200),
+                self.color_g.parse().unwrap_or(200),
+                self.color_b.parse().unwrap_or(200),
+            ),
+            snr_min: self.snr_min.parse().ok(),
+            snr_max: self.snr_max.parse().ok(),
+            doppler_min: self.doppler_min.parse().ok(),
+            doppler_max: self.doppler_max.parse().ok(),
+            visible: self.visible,
+        }
+    }
+}
+
+/// Text-input-backed form for the DBSCAN overlay's `eps`/`min_pts`
+/// parameters, with its own enable toggle — mirrors `AlertRuleForm`'s
+/// string-form-to-typed-payload split.
+#[derive(Debug, Clone)]
+struct ClusterForm {
+    enabled: bool,
+    eps: String,
+    min_pts: String,
+}
+
+impl Default for ClusterForm {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            eps: "0.08".into(),
+            min_pts: "3".into(),
+        }
+    }
+}
+
+impl ClusterForm {
+    fn update_field(&mut self, field: ClusterField, value: String) {
+        match field {
+            ClusterField::Eps => self.eps = value,
+            ClusterField::MinPts => self.min_pts = value,
+        }
+    }
+
+    /// `None` while clustering is disabled or the fields don't parse into a
+    /// usable `(eps, min_pts)` pair, so `DetectionMap` can skip the overlay
+    /// outright instead of running DBSCAN with nonsense parameters.
+    fn to_params(&self) -> Option<(f32, usize)> {
+        if !self.enabled {
+            return None;
+        }
+        let eps: f32 = self.eps.parse().ok()?;
+        let min_pts: usize = self.min_pts.parse().ok()?;
+        if eps <= 0.0 || min_pts == 0 {
+            return None;
+        }
+        Some((eps, min_pts))
+    }
+}
+
+/// Text-input-backed form for the PPI sweep beam's rotation rate.
+#[derive(Debug, Clone)]
+struct SweepForm {
+    rate_deg_per_sec: String,
+}
+
+impl Default for SweepForm {
+    fn default() -> Self {
+        Self {
+            rate_deg_per_sec: "90".into(),
+        }
+    }
+}
+
+impl SweepForm {
+    fn update_field(&mut self, field: SweepField, value: String) {
+        match field {
+            SweepField::RateDegPerSec => self.rate_deg_per_sec = value,
+        }
+    }
+
+    fn to_rate_deg_per_sec(&self) -> f32 {
+        self.rate_deg_per_sec.parse().unwrap_or(90.0)
+    }
 }
 
-#[derive(Debug, Serialize)]
-struct ScenarioConfig {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ScenarioConfig {
     taps: Option<u32>,
     range_bins: Option<u32>,
     doppler_bins: Option<u32>,
@@ -747,7 +1457,7 @@ struct ScenarioConfig {
 }
 
 #[derive(Debug, Clone, Deserialize)]
-struct VisualizationPayload {
+pub(crate) struct VisualizationPayload {
     #[serde(default)]
     power_profile: Vec<f32>,
     #[serde(default)]
@@ -758,6 +1468,15 @@ struct VisualizationPayload {
     detection_records: Vec<DetectionRecord>,
     #[serde(default)]
     scenario_metadata: Option<ScenarioMetadata>,
+    /// Flattened `range_doppler_range_bins × range_doppler_doppler_bins`
+    /// magnitude matrix, row-major by range bin, behind the `RangeDopplerMap`
+    /// heatmap.
+    #[serde(default)]
+    range_doppler_matrix: Vec<f32>,
+    #[serde(default)]
+    range_doppler_range_bins: usize,
+    #[serde(default)]
+    range_doppler_doppler_bins: usize,
 }
 
 #[derive(Clone)]
@@ -814,6 +1533,138 @@ impl canvas::Program<Message> for Waveform {
     }
 }
 
+/// Renders `matrix` (a flattened `range_bins × doppler_bins` magnitude surface)
+/// as a viridis-colored heatmap, near range at the bottom and far range at
+/// the top, with `DetectionRecord` markers overlaid at their true
+/// range/Doppler position.
+#[derive(Clone)]
+struct RangeDopplerMap {
+    matrix: Vec<f32>,
+    range_bins: usize,
+    doppler_bins: usize,
+    records: Vec<DetectionRecord>,
+    metadata: Option<ScenarioMetadata>,
+}
+
+impl canvas::Program<Message> for RangeDopplerMap {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        frame.fill_rectangle(
+            Point::ORIGIN,
+            bounds.size(),
+            Color::from_rgb(0.05, 0.05, 0.05),
+        );
+
+        if self.range_bins == 0
+            || self.doppler_bins == 0
+            || self.matrix.len() != self.range_bins * self.doppler_bins
+        {
+            return vec![frame.into_geometry()];
+        }
+
+        let peak = self.matrix.iter().cloned().fold(0.0f32, f32::max).max(1e-6);
+        let cell_width = bounds.width / self.doppler_bins as f32;
+        let cell_height = bounds.height / self.range_bins as f32;
+
+        for range_bin in 0..self.range_bins {
+            let y = bounds.height - (range_bin as f32 + 1.0) * cell_height;
+            for doppler_bin in 0..self.doppler_bins {
+                let value = self.matrix[range_bin * self.doppler_bins + doppler_bin];
+                let x = doppler_bin as f32 * cell_width;
+                frame.fill_rectangle(
+                    Point::new(x, y),
+                    Size::new(cell_width + 0.5, cell_height + 0.5),
+                    viridis_color(value / peak),
+                );
+            }
+        }
+
+        let max_range_m = self
+            .metadata
+            .as_ref()
+            .map(|metadata| metadata.area_width_km.max(metadata.area_height_km) * 1000.0)
+            .unwrap_or(self.range_bins as f32 * 10.0)
+            .max(1.0);
+        let max_doppler_mps = self
+            .metadata
+            .as_ref()
+            .map(|metadata| (metadata.platform_velocity_kmh / 3.6).abs())
+            .unwrap_or(50.0)
+            .max(1.0);
+
+        for tick in 0..=4 {
+            let fraction = tick as f32 / 4.0;
+            let y = bounds.height - fraction * bounds.height;
+            frame.fill_text(canvas::Text {
+                content: format!("{:.0} m", max_range_m * fraction),
+                position: Point::new(2.0, (y - 10.0).max(2.0)),
+                color: Color::WHITE,
+                size: Pixels(10.0),
+                ..canvas::Text::default()
+            });
+
+            let x = fraction * bounds.width;
+            frame.fill_text(canvas::Text {
+                content: format!("{:.0} m/s", -max_doppler_mps + 2.0 * max_doppler_mps * fraction),
+                position: Point::new(x, bounds.height - 12.0),
+                color: Color::WHITE,
+                size: Pixels(10.0),
+                ..canvas::Text::default()
+            });
+        }
+
+        for record in &self.records {
+            let range_fraction = (record.range / max_range_m).clamp(0.0, 1.0);
+            let doppler_fraction =
+                ((record.doppler + max_doppler_mps) / (2.0 * max_doppler_mps)).clamp(0.0, 1.0);
+            let position = Point::new(
+                doppler_fraction * bounds.width,
+                bounds.height - range_fraction * bounds.height,
+            );
+            let marker = Path::new(|builder| builder.circle(position, 3.0));
+            frame.stroke(
+                &marker,
+                Stroke::default().with_width(1.5).with_color(Color::WHITE),
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Small viridis-style perceptual colormap: five RGB control points sampled
+/// from matplotlib's viridis, linearly interpolated by `value` in `[0, 1]`.
+fn viridis_color(value: f32) -> Color {
+    const STOPS: [(f32, f32, f32); 5] = [
+        (0.267, 0.005, 0.329),
+        (0.230, 0.322, 0.546),
+        (0.128, 0.567, 0.551),
+        (0.369, 0.789, 0.383),
+        (0.993, 0.906, 0.144),
+    ];
+    let clamped = value.clamp(0.0, 1.0);
+    let segments = STOPS.len() - 1;
+    let scaled = clamped * segments as f32;
+    let index = (scaled.floor() as usize).min(segments - 1);
+    let local = scaled - index as f32;
+    let (r0, g0, b0) = STOPS[index];
+    let (r1, g1, b1) = STOPS[index + 1];
+    Color::from_rgb(
+        r0 + (r1 - r0) * local,
+        g0 + (g1 - g0) * local,
+        b0 + (b1 - b0) * local,
+    )
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum DetectionViewMode {
     Polar,
@@ -846,6 +1697,17 @@ struct DetectionMap {
     records: Vec<DetectionRecord>,
     view: DetectionViewState,
     metadata: Option<ScenarioMetadata>,
+    /// Indices into `records` with an unacknowledged alert, flagged with a
+    /// ring around the blip.
+    alerted_indices: HashSet<usize>,
+    classes: Vec<ClassificationRule>,
+    tracks: Vec<RenderedTrack>,
+    /// `(eps, min_pts)` for the DBSCAN overlay, or `None` while clustering is
+    /// disabled.
+    cluster_params: Option<(f32, usize)>,
+    /// PPI sweep beam and persistence trail, or `None` while live mode is
+    /// off and the static `records` snapshot is rendered instead.
+    live_sweep: Option<LiveSweepOverlay>,
 }
 
 impl DetectionMap {
@@ -853,15 +1715,34 @@ impl DetectionMap {
         records: &[DetectionRecord],
         view: DetectionViewState,
         metadata: Option<ScenarioMetadata>,
+        alerted_indices: HashSet<usize>,
+        classes: Vec<ClassificationRule>,
+        tracks: Vec<RenderedTrack>,
+        cluster_params: Option<(f32, usize)>,
+        live_sweep: Option<LiveSweepOverlay>,
     ) -> Self {
         Self {
             records: records.to_vec(),
             view,
             metadata,
+            alerted_indices,
+            classes,
+            tracks,
+            cluster_params,
+            live_sweep,
         }
     }
 }
 
+/// Streamed-mode rendering input for `DetectionMap`: the sweep beam's
+/// current angle and every buffered detection paired with its age, so the
+/// canvas can fade brightness by age instead of drawing one static frame.
+struct LiveSweepOverlay {
+    sweep_angle_deg: f32,
+    aged_detections: Vec<(DetectionRecord, f32)>,
+    window_secs: f32,
+}
+
 impl canvas::Program<Message> for DetectionMap {
     type State = ();
 
@@ -871,7 +1752,7 @@ impl canvas::Program<Message> for DetectionMap {
         renderer: &Renderer,
         _theme: &Theme,
         bounds: Rectangle,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
     ) -> Vec<Geometry> {
         let mut frame = Frame::new(renderer, bounds.size());
         frame.fill_rectangle(
@@ -884,6 +1765,7 @@ impl canvas::Program<Message> for DetectionMap {
         let base_radius = bounds.width.min(bounds.height) / 2.0 - 12.0;
         let zoom = self.view.zoom.clamp(0.6, 2.5);
         let radius = (base_radius * zoom).max(16.0);
+        let rotation_rad = self.view.rotation.to_radians();
 
         if self.view.show_grid {
             match self.view.mode {
@@ -908,6 +1790,26 @@ impl canvas::Program<Message> for DetectionMap {
                             .with_color(Color::from_rgb(0.35, 0.35, 0.45))
                             .with_width(1.0),
                     );
+
+                    // Compass bearing labels around the ring, rotated along
+                    // with the scene so "up" always reads as the view's
+                    // current heading rather than true north on screen.
+                    for compass_deg in (0..360).step_by(45) {
+                        let bearing = Angle::from_degrees(compass_deg as f32);
+                        let bearing_rad = bearing.to_radians();
+                        let label_point = Point::new(
+                            center.x + (radius + 14.0) * bearing_rad.sin(),
+                            center.y - (radius + 14.0) * bearing_rad.cos(),
+                        );
+                        let rotated = rotate_point(label_point, center, rotation_rad);
+                        frame.fill_text(canvas::Text {
+                            content: bearing.compass_label().to_string(),
+                            position: rotated,
+                            color: Color::from_rgb(0.55, 0.55, 0.65),
+                            size: Pixels(11.0),
+                            ..canvas::Text::default()
+                        });
+                    }
                 }
                 DetectionViewMode::Cartesian => {
                     let grid = Path::new(|builder| {
@@ -943,62 +1845,549 @@ impl canvas::Program<Message> for DetectionMap {
             }
         }
 
-        let metadata_range = self
-            .metadata
-            .as_ref()
-            .map(|meta| meta.area_width_km.max(meta.area_height_km) * 1000.0)
-            .unwrap_or(0.0);
-        let max_range = self
-            .records
-            .iter()
-            .map(|record| record.range)
-            .fold(0.0, f32::max)
-            .max(1.0);
-        let display_range = metadata_range.max(max_range).max(1.0);
-        let max_doppler = self
-            .records
-            .iter()
-            .map(|record| record.doppler.abs())
-            .fold(0.0, f32::max)
-            .max(0.5);
-        let rotation_rad = self.view.rotation.to_radians();
+        let display_range = detection_display_range(&self.records, self.metadata.as_ref());
+        let max_doppler = detection_max_doppler(&self.records);
+        // Screen positions actually drawn this frame, paired with their
+        // index into `self.records` — fed to the hit-test grid below.
+        let mut drawn_markers: Vec<(usize, Point)> = Vec::new();
 
-        for record in &self.records {
-            let normalized_range = (record.range / display_range).clamp(0.0, 1.0);
-            let normalized_doppler = if max_doppler > 0.0 {
-                (record.doppler / max_doppler).clamp(-1.0, 1.0)
-            } else {
-                0.0
-            };
-            let (x, y) = match self.view.mode {
-                DetectionViewMode::Polar => {
-                    let point_radius = normalized_range * radius;
-                    let angle = normalized_doppler * PI;
-                    (
-                        center.x + point_radius * angle.cos(),
-                        center.y - point_radius * angle.sin(),
+        // DBSCAN over normalized (range, doppler) unit positions, the same
+        // plane the markers themselves are projected from, so the clusters
+        // it finds don't shift with zoom or rotation.
+        let cluster_labels = self.cluster_params.map(|(eps, min_pts)| {
+            let points: Vec<(f32, f32)> = self
+                .records
+                .iter()
+                .map(|record| {
+                    detection_unit_position(
+                        record.range,
+                        record.doppler,
+                        self.view.mode,
+                        display_range,
+                        max_doppler,
                     )
+                })
+                .collect();
+            clustering::dbscan(&points, eps, min_pts)
+        });
+
+        if let Some(live) = &self.live_sweep {
+            // Persistence trail: every buffered detection faded by age,
+            // drawn behind the current frame's markers.
+            for (record, age) in &live.aged_detections {
+                if *age > live.window_secs {
+                    continue;
                 }
-                DetectionViewMode::Cartesian => (
-                    center.x + (normalized_range * 2.0 - 1.0) * radius,
-                    center.y - normalized_doppler * radius,
-                ),
-            };
+                let (ux, uy) = detection_unit_position(
+                    record.range,
+                    record.doppler,
+                    self.view.mode,
+                    display_range,
+                    max_doppler,
+                );
+                let (x, y) = (center.x + ux * radius, center.y - uy * radius);
+                let rotated = rotate_point(Point::new(x, y), center, rotation_rad);
+                let brightness = (1.0 - age / live.window_secs).clamp(0.0, 1.0);
+                let marker_radius = 2.0 + (record.snr.min(12.0) * 0.15);
+                let trail_marker = Path::new(|builder| builder.circle(rotated, marker_radius));
+                frame.fill(
+                    &trail_marker,
+                    Color::from_rgba(0.3, 0.85, 0.3, brightness * 0.8),
+                );
+            }
+
+            if self.view.mode == DetectionViewMode::Polar {
+                let beam_rad = live.sweep_angle_deg.to_radians();
+                let beam_end = Point::new(
+                    center.x + radius * beam_rad.sin(),
+                    center.y - radius * beam_rad.cos(),
+                );
+                let beam = Path::new(|builder| {
+                    builder.move_to(center);
+                    builder.line_to(beam_end);
+                });
+                frame.stroke(
+                    &beam,
+                    Stroke::default()
+                        .with_width(2.0)
+                        .with_color(Color::from_rgba(0.3, 1.0, 0.3, 0.8)),
+                );
+            }
+        }
+
+        for (index, record) in self.records.iter().enumerate() {
+            let class = classification::classify(record, &self.classes);
+            if class.is_some_and(|class| !class.visible) {
+                continue;
+            }
+            let (ux, uy) = detection_unit_position(
+                record.range,
+                record.doppler,
+                self.view.mode,
+                display_range,
+                max_doppler,
+            );
+            let (x, y) = (center.x + ux * radius, center.y - uy * radius);
             let rotated = rotate_point(Point::new(x, y), center, rotation_rad);
+            drawn_markers.push((index, rotated));
             let marker_radius = 3.0 + (record.snr.min(12.0) * 0.2);
             let marker = Path::new(|builder| builder.circle(rotated, marker_radius));
-            let color = Color::from_rgb(
-                0.25 + (record.snr / 40.0).clamp(0.0, 0.5),
-                0.5 - (record.snr / 70.0).clamp(0.0, 0.3),
-                0.2,
+            let color = class.map(|class| class.iced_color()).unwrap_or_else(|| {
+                Color::from_rgb(
+                    0.25 + (record.snr / 40.0).clamp(0.0, 0.5),
+                    0.5 - (record.snr / 70.0).clamp(0.0, 0.3),
+                    0.2,
+                )
+            });
+            let is_noise = cluster_labels
+                .as_ref()
+                .is_some_and(|labels| labels[index] == clustering::NOISE);
+            frame.fill(&marker, if is_noise { Color { a: 0.35, ..color } } else { color });
+
+            if self.alerted_indices.contains(&index) {
+                let ring = Path::new(|builder| builder.circle(rotated, marker_radius + 5.0));
+                frame.stroke(
+                    &ring,
+                    Stroke::default()
+                        .with_width(2.0)
+                        .with_color(Color::from_rgb(0.9, 0.15, 0.15)),
+                );
+            }
+        }
+
+        for track in &self.tracks {
+            if track.points.len() < 2 {
+                continue;
+            }
+            let screen_points: Vec<Point> = track
+                .points
+                .iter()
+                .map(|&(range, doppler)| {
+                    let (ux, uy) = detection_unit_position(
+                        range,
+                        doppler,
+                        self.view.mode,
+                        display_range,
+                        max_doppler,
+                    );
+                    let (x, y) = (center.x + ux * radius, center.y - uy * radius);
+                    rotate_point(Point::new(x, y), center, rotation_rad)
+                })
+                .collect();
+            let base_color = Color::from_rgb8(track.color.0, track.color.1, track.color.2);
+
+            // Fading polyline: oldest segment most transparent, newest opaque.
+            for window in screen_points.windows(2).enumerate() {
+                let (segment_index, points) = window;
+                let alpha = (segment_index + 1) as f32 / (screen_points.len() - 1) as f32;
+                let segment = Path::new(|builder| {
+                    builder.move_to(points[0]);
+                    builder.line_to(points[1]);
+                });
+                frame.stroke(
+                    &segment,
+                    Stroke::default()
+                        .with_width(2.0)
+                        .with_color(Color { a: alpha, ..base_color }),
+                );
+            }
+
+            if let Some(&latest) = screen_points.last() {
+                frame.fill_text(canvas::Text {
+                    content: format!("T{}", track.id),
+                    position: Point::new(latest.x + 6.0, latest.y - 6.0),
+                    color: base_color,
+                    size: Pixels(11.0),
+                    ..canvas::Text::default()
+                });
+            }
+        }
+
+        if let Some(labels) = &cluster_labels {
+            let mut clusters: std::collections::HashMap<i32, Vec<(Point, f32)>> =
+                std::collections::HashMap::new();
+            for &(record_index, point) in &drawn_markers {
+                let label = labels[record_index];
+                if label == clustering::NOISE {
+                    continue;
+                }
+                clusters
+                    .entry(label)
+                    .or_default()
+                    .push((point, self.records[record_index].snr));
+            }
+            let mut cluster_ids: Vec<&i32> = clusters.keys().collect();
+            cluster_ids.sort();
+            for cluster_id in cluster_ids {
+                let members = &clusters[cluster_id];
+                if members.len() < 3 {
+                    continue;
+                }
+                let screen_points: Vec<(f32, f32)> =
+                    members.iter().map(|(point, _)| (point.x, point.y)).collect();
+                let hull = clustering::convex_hull(&screen_points);
+                if hull.len() < 3 {
+                    continue;
+                }
+                let hull_path = Path::new(|builder| {
+                    builder.move_to(Point::new(hull[0].0, hull[0].1));
+                    for &(x, y) in &hull[1..] {
+                        builder.line_to(Point::new(x, y));
+                    }
+                    builder.close();
+                });
+                frame.stroke(
+                    &hull_path,
+                    Stroke::default()
+                        .with_width(1.5)
+                        .with_color(Color::from_rgb(0.95, 0.85, 0.25)),
+                );
+
+                let count = members.len();
+                let (sum_x, sum_y, sum_snr) = members.iter().fold(
+                    (0.0, 0.0, 0.0),
+                    |(sum_x, sum_y, sum_snr), (point, snr)| {
+                        (sum_x + point.x, sum_y + point.y, sum_snr + snr)
+                    },
+                );
+                let centroid = Point::new(sum_x / count as f32, sum_y / count as f32);
+                frame.fill_text(canvas::Text {
+                    content: format!("n={} SNR {:.1} dB", count, sum_snr / count as f32),
+                    position: centroid,
+                    color: Color::from_rgb(0.95, 0.85, 0.25),
+                    size: Pixels(11.0),
+                    ..canvas::Text::default()
+                });
+            }
+        }
+
+        if let Some(hovered) = cursor
+            .position_in(bounds)
+            .and_then(|cursor_pos| nearest_marker(&drawn_markers, cursor_pos))
+        {
+            let (record_index, point) = drawn_markers[hovered];
+            let record = &self.records[record_index];
+
+            let highlight = Path::new(|builder| builder.circle(point, 10.0));
+            frame.stroke(
+                &highlight,
+                Stroke::default()
+                    .with_width(2.0)
+                    .with_color(Color::WHITE),
+            );
+
+            let tooltip_origin = Point::new(point.x + 14.0, point.y - 14.0);
+            frame.fill_rectangle(
+                tooltip_origin,
+                Size::new(150.0, 54.0),
+                Color::from_rgba(0.05, 0.05, 0.05, 0.9),
             );
-            frame.fill(&marker, color);
+            for (line, text_content) in [
+                format!("Range {:.1} m", record.range),
+                format!("Doppler {:.2} m/s", record.doppler),
+                format!("SNR {:.2} dB", record.snr),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                frame.fill_text(canvas::Text {
+                    content: text_content,
+                    position: Point::new(tooltip_origin.x + 8.0, tooltip_origin.y + 6.0 + line as f32 * 16.0),
+                    color: Color::WHITE,
+                    size: Pixels(12.0),
+                    ..canvas::Text::default()
+                });
+            }
         }
 
         vec![frame.into_geometry()]
     }
 }
 
+/// Largest range `DetectionMap` normalizes against: the larger of the
+/// surveillance area from scenario metadata (if present) and the farthest
+/// detection, and never below 1 m.
+fn detection_display_range(records: &[DetectionRecord], metadata: Option<&ScenarioMetadata>) -> f32 {
+    let metadata_range = metadata
+        .map(|meta| meta.area_width_km.max(meta.area_height_km) * 1000.0)
+        .unwrap_or(0.0);
+    let max_range = records
+        .iter()
+        .map(|record| record.range)
+        .fold(0.0, f32::max)
+        .max(1.0);
+    metadata_range.max(max_range).max(1.0)
+}
+
+/// Largest |doppler| `DetectionMap` normalizes against.
+fn detection_max_doppler(records: &[DetectionRecord]) -> f32 {
+    records
+        .iter()
+        .map(|record| record.doppler.abs())
+        .fold(0.0, f32::max)
+        .max(0.5)
+}
+
+/// Projects a (range, doppler) pair to unit-radius (x, y) offsets from the
+/// scene origin, before zoom/rotation are applied — shared by
+/// `DetectionMap`'s detection and track rendering and the sonifier's
+/// bearing calculation so none of them ever drift apart.
+fn detection_unit_position(
+    range: f32,
+    doppler: f32,
+    mode: DetectionViewMode,
+    display_range: f32,
+    max_doppler: f32,
+) -> (f32, f32) {
+    let normalized_range = (range / display_range).clamp(0.0, 1.0);
+    let normalized_doppler = if max_doppler > 0.0 {
+        (doppler / max_doppler).clamp(-1.0, 1.0)
+    } else {
+        0.0
+    };
+    match mode {
+        DetectionViewMode::Polar => {
+            let angle = normalized_doppler * PI;
+            (normalized_range * angle.cos(), normalized_range * angle.sin())
+        }
+        DetectionViewMode::Cartesian => (normalized_range * 2.0 - 1.0, normalized_doppler),
+    }
+}
+
+/// Builds one audio cue per detection, panned by its bearing from the
+/// platform at the scene origin (`atan2(x, y)` over the same unit position
+/// `DetectionMap` projects for rendering).
+fn sonification_cues(
+    records: &[DetectionRecord],
+    mode: DetectionViewMode,
+    metadata: Option<&ScenarioMetadata>,
+) -> Vec<ToneCue> {
+    let display_range = detection_display_range(records, metadata);
+    let max_doppler = detection_max_doppler(records);
+    records
+        .iter()
+        .map(|record| {
+            let (x, y) =
+                detection_unit_position(record.range, record.doppler, mode, display_range, max_doppler);
+            let azimuth_rad = x.atan2(y);
+            ToneCue::new(record.doppler, record.snr, azimuth_rad)
+        })
+        .collect()
+}
+
+/// Spoken summary of a telemetry tick, e.g. "3 detections, strongest SNR 14
+/// dB at 2 kilometers, closing".
+fn speech_summary(records: &[DetectionRecord]) -> String {
+    let Some(strongest) = records
+        .iter()
+        .max_by(|a, b| a.snr.total_cmp(&b.snr))
+    else {
+        return "No detections".into();
+    };
+    format!(
+        "{} detection{}, strongest SNR {} dB at {} kilometers, {}",
+        records.len(),
+        if records.len() == 1 { "" } else { "s" },
+        strongest.snr.round(),
+        (strongest.range / 1000.0 * 10.0).round() / 10.0,
+        if strongest.doppler >= 0.0 { "closing" } else { "receding" }
+    )
+}
+
+/// Spoken announcement for a single newly-arrived detection.
+fn detection_announcement(record: &DetectionRecord) -> String {
+    let bearing = record.bearing();
+    format!(
+        "New detection, SNR {} dB at {} kilometers, bearing {} {}",
+        record.snr.round(),
+        (record.range / 1000.0 * 10.0).round() / 10.0,
+        bearing.degrees().round(),
+        bearing.compass_label()
+    )
+}
+
+/// Renders the saved-scenario catalog as a name/tags row plus a Load button
+/// per entry, matching the compact list style used elsewhere in the form.
+fn scenario_library_list(scenarios: &[SavedScenario]) -> Element<'_, Message> {
+    if scenarios.is_empty() {
+        return Container::new(text("No saved scenarios yet").size(12)).into();
+    }
+    let list = scenarios.iter().fold(Column::new().spacing(6), |col, saved| {
+        col.push(
+            row![
+                column![
+                    text(saved.name.clone()).size(13),
+                    text(if saved.tags.is_empty() {
+                        "no tags".into()
+                    } else {
+                        saved.tags.join(", ")
+                    })
+                    .size(11),
+                ]
+                .width(Length::Fill),
+                button("Load")
+                    .on_press(Message::LoadScenario(saved.id))
+                    .padding(4),
+            ]
+            .align_y(Alignment::Center)
+            .spacing(8),
+        )
+    });
+    Container::new(scrollable(list).height(Length::Fixed(160.0))).into()
+}
+
+/// Renders the active-alerts deque newest first, color-coded by severity,
+/// with an Acknowledge button per unacknowledged entry.
+fn alert_panel_list(active_alerts: &VecDeque<ActiveAlert>) -> Element<'_, Message> {
+    if active_alerts.is_empty() {
+        return Container::new(text("No alerts").size(12)).into();
+    }
+    let list = active_alerts
+        .iter()
+        .rev()
+        .fold(Column::new().spacing(4), |col, alert| {
+            let label = text(format!(
+                "[{}] {} — detection #{}{}",
+                alert.severity.label(),
+                alert.rule.label(),
+                alert.detection_index + 1,
+                if alert.acknowledged { " (ack)" } else { "" }
+            ))
+            .size(12)
+            .color(alert.severity.color());
+            let entry: Element<'_, Message> = if alert.acknowledged {
+                row![label].into()
+            } else {
+                row![
+                    label,
+                    button("Acknowledge")
+                        .on_press(Message::AcknowledgeAlert(alert.id))
+                        .padding(2),
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .into()
+            };
+            col.push(entry)
+        });
+    Container::new(list).into()
+}
+
+/// Renders one row of bound/color text inputs per classification layer, so
+/// operators can retune the palette that `DetectionMap` classifies against.
+fn class_edit_rows(class_forms: &[ClassFormRow]) -> Element<'_, Message> {
+    let rows = class_forms
+        .iter()
+        .enumerate()
+        .fold(Column::new().spacing(6), |col, (index, class_row)| {
+            col.push(
+                column![
+                    text(class_row.name.clone()).size(13),
+                    row![
+                        text_input("R", &class_row.color_r)
+                            .on_input(move |v| Message::ClassFieldChanged(
+                                index,
+                                ClassField::ColorR,
+                                v
+                            ))
+                            .width(Length::Fixed(48.0)),
+                        text_input("G", &class_row.color_g)
+                            .on_input(move |v| Message::ClassFieldChanged(
+                                index,
+                                ClassField::ColorG,
+                                v
+                            ))
+                            .width(Length::Fixed(48.0)),
+                        text_input("B", &class_row.color_b)
+                            .on_input(move |v| Message::ClassFieldChanged(
+                                index,
+                                ClassField::ColorB,
+                                v
+                            ))
+                            .width(Length::Fixed(48.0)),
+                        text_input("SNR min", &class_row.snr_min)
+                            .on_input(move |v| Message::ClassFieldChanged(
+                                index,
+                                ClassField::SnrMin,
+                                v
+                            ))
+                            .width(Length::Fixed(64.0)),
+                        text_input("SNR max", &class_row.snr_max)
+                            .on_input(move |v| Message::ClassFieldChanged(
+                                index,
+                                ClassField::SnrMax,
+                                v
+                            ))
+                            .width(Length::Fixed(64.0)),
+                        text_input("Doppler min", &class_row.doppler_min)
+                            .on_input(move |v| Message::ClassFieldChanged(
+                                index,
+                                ClassField::DopplerMin,
+                                v
+                            ))
+                            .width(Length::Fixed(72.0)),
+                        text_input("Doppler max", &class_row.doppler_max)
+                            .on_input(move |v| Message::ClassFieldChanged(
+                                index,
+                                ClassField::DopplerMax,
+                                v
+                            ))
+                            .width(Length::Fixed(72.0)),
+                    ]
+                    .spacing(4),
+                ]
+                .spacing(2),
+            )
+        });
+    Container::new(rows).into()
+}
+
+/// Side length of the uniform grid cell used to bucket `drawn_markers` for
+/// hit-testing, so picking a marker under the cursor is a 3x3-cell lookup
+/// instead of a scan over every detection.
+const HIT_TEST_CELL_SIZE: f32 = 32.0;
+/// Markers farther than this from the cursor (in pixels) aren't picked.
+const HIT_TEST_RADIUS: f32 = 12.0;
+
+fn hit_test_cell(point: Point) -> (i32, i32) {
+    (
+        (point.x / HIT_TEST_CELL_SIZE).floor() as i32,
+        (point.y / HIT_TEST_CELL_SIZE).floor() as i32,
+    )
+}
+
+/// Finds the marker in `drawn_markers` closest to `cursor_pos`, within
+/// `HIT_TEST_RADIUS` pixels. Builds a uniform grid keyed by screen cell once
+/// per call and only scans the cell under the cursor plus its eight
+/// neighbors, so this stays cheap with thousands of detections.
+fn nearest_marker(drawn_markers: &[(usize, Point)], cursor_pos: Point) -> Option<usize> {
+    let mut grid: std::collections::HashMap<(i32, i32), Vec<usize>> = std::collections::HashMap::new();
+    for (marker_index, (_, point)) in drawn_markers.iter().enumerate() {
+        grid.entry(hit_test_cell(*point)).or_default().push(marker_index);
+    }
+
+    let (cell_x, cell_y) = hit_test_cell(cursor_pos);
+    let mut best: Option<(usize, f32)> = None;
+    for dx in -1..=1 {
+        for dy in -1..=1 {
+            let Some(marker_indices) = grid.get(&(cell_x + dx, cell_y + dy)) else {
+                continue;
+            };
+            for &marker_index in marker_indices {
+                let (_, point) = drawn_markers[marker_index];
+                let dist_sq = (point.x - cursor_pos.x).powi(2) + (point.y - cursor_pos.y).powi(2);
+                if dist_sq > HIT_TEST_RADIUS * HIT_TEST_RADIUS {
+                    continue;
+                }
+                if best.map_or(true, |(_, best_dist)| dist_sq < best_dist) {
+                    best = Some((marker_index, dist_sq));
+                }
+            }
+        }
+    }
+    best.map(|(marker_index, _)| marker_index)
+}
+
 fn rotate_point(point: Point, center: Point, angle_rad: f32) -> Point {
     let sin = angle_rad.sin();
     let cos = angle_rad.cos();
@@ -0,0 +1,101 @@
+use gmticore::agp_interface::DetectionRecord;
+use std::collections::VecDeque;
+
+/// One streamed frame of detections, stamped with the wall-clock time it was
+/// received so `PersistenceBuffer` can fade and expire it independently of
+/// the radar's own per-detection `timestamp` field.
+struct TimestampedFrame {
+    received_at: f64,
+    records: Vec<DetectionRecord>,
+}
+
+/// Fixed-window ring buffer of recently-received detection frames, feeding
+/// `DetectionMap`'s PPI persistence trail: newest frames render bright,
+/// older ones fade toward the background, and anything older than
+/// `window_secs` is evicted so a long-running stream never grows unbounded.
+pub(crate) struct PersistenceBuffer {
+    frames: VecDeque<TimestampedFrame>,
+    window_secs: f64,
+}
+
+impl PersistenceBuffer {
+    pub fn new(window_secs: f64) -> Self {
+        Self {
+            frames: VecDeque::new(),
+            window_secs,
+        }
+    }
+
+    /// Pushes a freshly-arrived frame and evicts anything that has aged past
+    /// the window.
+    pub fn push(&mut self, records: Vec<DetectionRecord>, now: f64) {
+        self.frames.push_back(TimestampedFrame {
+            received_at: now,
+            records,
+        });
+        self.evict(now);
+    }
+
+    fn evict(&mut self, now: f64) {
+        while let Some(oldest) = self.frames.front() {
+            if now - oldest.received_at > self.window_secs {
+                self.frames.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Every buffered detection paired with its age in seconds since it
+    /// arrived, for brightness-by-age rendering. Does not evict on its own —
+    /// callers drive eviction by calling `push` on each new frame.
+    pub fn aged_detections(&self, now: f64) -> Vec<(DetectionRecord, f32)> {
+        self.frames
+            .iter()
+            .flat_map(|frame| {
+                let age = (now - frame.received_at).max(0.0) as f32;
+                frame
+                    .records
+                    .iter()
+                    .cloned()
+                    .map(move |record| (record, age))
+            })
+            .collect()
+    }
+
+    pub fn window_secs(&self) -> f64 {
+        self.window_secs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> DetectionRecord {
+        DetectionRecord::new(0.0, 500.0, 5.0, 10.0, 0.0, 0.0)
+    }
+
+    #[test]
+    fn aged_detections_reports_age_since_arrival() {
+        let mut buffer = PersistenceBuffer::new(10.0);
+        buffer.push(vec![record()], 100.0);
+        let aged = buffer.aged_detections(104.0);
+        assert_eq!(aged.len(), 1);
+        assert_eq!(aged[0].1, 4.0);
+    }
+
+    #[test]
+    fn frames_older_than_the_window_are_evicted() {
+        let mut buffer = PersistenceBuffer::new(5.0);
+        buffer.push(vec![record()], 100.0);
+        buffer.push(vec![record()], 110.0);
+        assert_eq!(buffer.aged_detections(110.0).len(), 1);
+    }
+
+    #[test]
+    fn an_empty_buffer_has_no_aged_detections() {
+        let buffer = PersistenceBuffer::new(10.0);
+        assert!(buffer.aged_detections(0.0).is_empty());
+    }
+}
@@ -0,0 +1,23 @@
+use tts::Tts;
+
+/// Wraps the platform TTS backend (speech-dispatcher/SAPI/AVSpeechSynthesizer
+/// via the `tts` crate) so the visualizer can be operated eyes-free.
+pub struct Speaker {
+    tts: Tts,
+}
+
+impl Speaker {
+    /// Opens the platform's default speech backend, or `None` if none is
+    /// available (e.g. headless CI).
+    pub fn try_new() -> Option<Self> {
+        Tts::default().ok().map(|tts| Self { tts })
+    }
+
+    /// Queues `message` for speech without interrupting what's already
+    /// being announced.
+    pub fn speak(&mut self, message: &str) {
+        if let Err(err) = self.tts.speak(message, false) {
+            eprintln!("[speech] failed to speak: {}", err);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{Message, VisualizationPayload};
+use futures_util::{SinkExt, StreamExt};
+use iced::subscription::{self, Subscription};
+use std::time::Duration;
+use tokio_tungstenite::tungstenite;
+
+/// Backend address for the push-based telemetry socket; falls back to HTTP
+/// polling (see `application_subscription`) when nothing answers here.
+const STREAM_URL: &str = "ws://127.0.0.1:9000/stream";
+/// Reconnect delays after a dropped connection, capped at the last entry.
+const RECONNECT_BACKOFF_SECS: [u64; 4] = [1, 2, 5, 10];
+
+/// Subscribes to `/stream` and emits `Message::PayloadFetched` for every
+/// frame the backend pushes, reconnecting with backoff on disconnect.
+pub fn connect() -> Subscription<Message> {
+    subscription::channel("telemetry-stream", 100, |mut output| async move {
+        let mut attempt = 0usize;
+        loop {
+            match tokio_tungstenite::connect_async(STREAM_URL).await {
+                Ok((socket, _)) => {
+                    attempt = 0;
+                    let (_, mut reader) = socket.split();
+                    while let Some(frame) = reader.next().await {
+                        let message = match frame {
+                            Ok(tungstenite::Message::Text(text)) => {
+                                serde_json::from_str::<VisualizationPayload>(&text)
+                                    .map_err(|err| err.to_string())
+                            }
+                            Ok(tungstenite::Message::Close(_)) => break,
+                            Ok(_) => continue,
+                            Err(err) => Err(err.to_string()),
+                        };
+                        let is_err = message.is_err();
+                        let _ = output.send(Message::PayloadFetched(message)).await;
+                        if is_err {
+                            break;
+                        }
+                    }
+                }
+                Err(err) => {
+                    let _ = output
+                        .send(Message::PayloadFetched(Err(format!(
+                            "stream unavailable: {err}"
+                        ))))
+                        .await;
+                }
+            }
+
+            let delay = RECONNECT_BACKOFF_SECS[attempt.min(RECONNECT_BACKOFF_SECS.len() - 1)];
+            attempt += 1;
+            tokio::time::sleep(Duration::from_secs(delay)).await;
+        }
+    })
+}
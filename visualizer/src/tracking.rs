@@ -0,0 +1,371 @@
+use gmticore::agp_interface::DetectionRecord;
+use std::collections::VecDeque;
+
+/// History window kept per track for the fixed-lag smoother. Small on
+/// purpose: the motion model is linear so a wider window buys little extra
+/// denoising for a lot more per-tick solving.
+const TRACK_WINDOW: usize = 8;
+/// Consecutive frames a track may go unmatched (coasting on its prediction)
+/// before it's deleted.
+const MAX_MISSED_FRAMES: u32 = 3;
+/// Association gate in normalized (range, doppler) units; see `gated_distance`.
+const GATE_DISTANCE: f32 = 1.0;
+const RANGE_GATE_M: f32 = 150.0;
+const DOPPLER_GATE_MPS: f32 = 6.0;
+/// Relative weight of the motion residual against the measurement residual
+/// in the smoother's normal equations: higher trusts the constant-velocity
+/// model more than the raw measurement.
+const PROCESS_WEIGHT: f64 = 0.6;
+
+/// One raw (range, doppler) sample recorded at `timestamp`, either from a
+/// real detection or a coasted prediction while a track is unmatched.
+#[derive(Debug, Clone, Copy)]
+struct TrackState {
+    timestamp: f64,
+    range: f32,
+    doppler: f32,
+}
+
+/// A persistent track formed by associating detections across frames.
+#[derive(Debug, Clone)]
+pub(crate) struct Track {
+    pub id: u64,
+    pub color: (u8, u8, u8),
+    history: VecDeque<TrackState>,
+    missed: u32,
+}
+
+impl Track {
+    fn predict(&self, now: f64) -> (f32, f32) {
+        let last = *self.history.back().expect("track always has a state");
+        let dt = (now - last.timestamp) as f32;
+        (last.range + last.doppler * dt, last.doppler)
+    }
+
+    fn push_state(&mut self, state: TrackState) {
+        self.history.push_back(state);
+        if self.history.len() > TRACK_WINDOW {
+            self.history.pop_front();
+        }
+    }
+}
+
+/// A track's smoothed history, ready to render as a fading polyline plus an
+/// ID label at its most recent point.
+#[derive(Debug, Clone)]
+pub(crate) struct RenderedTrack {
+    pub id: u64,
+    pub color: (u8, u8, u8),
+    /// Smoothed (range, doppler) points, oldest first.
+    pub points: Vec<(f32, f32)>,
+    /// Smoothed range rate (m/s) from the fixed-lag solve: the slope of range
+    /// over time, positive while range grows.
+    pub velocity: f32,
+}
+
+/// Maintains tracks across ticks: predicts, associates new detections via
+/// greedy global-nearest-neighbor within a gate, coasts or drops misses, and
+/// spawns tracks for anything left unmatched.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Tracker {
+    tracks: Vec<Track>,
+    next_id: u64,
+}
+
+impl Tracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, detections: &[DetectionRecord], now: f64) {
+        let predictions: Vec<(f32, f32)> = self.tracks.iter().map(|track| track.predict(now)).collect();
+
+        let mut candidates: Vec<(usize, usize, f32)> = Vec::new();
+        for (track_index, prediction) in predictions.iter().enumerate() {
+            for (detection_index, detection) in detections.iter().enumerate() {
+                let distance = gated_distance(*prediction, (detection.range, detection.doppler));
+                if distance <= GATE_DISTANCE {
+                    candidates.push((track_index, detection_index, distance));
+                }
+            }
+        }
+        candidates.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+        let mut matched_track = vec![false; self.tracks.len()];
+        let mut matched_detection = vec![false; detections.len()];
+        let mut assignments = vec![None; self.tracks.len()];
+        for (track_index, detection_index, _) in candidates {
+            if matched_track[track_index] || matched_detection[detection_index] {
+                continue;
+            }
+            matched_track[track_index] = true;
+            matched_detection[detection_index] = true;
+            assignments[track_index] = Some(detection_index);
+        }
+
+        for (track_index, track) in self.tracks.iter_mut().enumerate() {
+            match assignments[track_index] {
+                Some(detection_index) => {
+                    let detection = &detections[detection_index];
+                    track.push_state(TrackState {
+                        timestamp: now,
+                        range: detection.range,
+                        doppler: detection.doppler,
+                    });
+                    track.missed = 0;
+                }
+                None => {
+                    let (predicted_range, predicted_doppler) = predictions[track_index];
+                    track.push_state(TrackState {
+                        timestamp: now,
+                        range: predicted_range,
+                        doppler: predicted_doppler,
+                    });
+                    track.missed += 1;
+                }
+            }
+        }
+        self.tracks.retain(|track| track.missed <= MAX_MISSED_FRAMES);
+
+        for (detection_index, detection) in detections.iter().enumerate() {
+            if matched_detection[detection_index] {
+                continue;
+            }
+            let id = self.next_id;
+            self.next_id += 1;
+            let mut history = VecDeque::with_capacity(TRACK_WINDOW);
+            history.push_back(TrackState {
+                timestamp: now,
+                range: detection.range,
+                doppler: detection.doppler,
+            });
+            self.tracks.push(Track {
+                id,
+                color: palette_color(id),
+                history,
+                missed: 0,
+            });
+        }
+    }
+
+    /// Smooths every track's history window and returns it for rendering.
+    pub fn rendered_tracks(&self) -> Vec<RenderedTrack> {
+        self.tracks
+            .iter()
+            .map(|track| {
+                let timestamps: Vec<f64> = track.history.iter().map(|s| s.timestamp).collect();
+                let ranges: Vec<f64> = track.history.iter().map(|s| s.range as f64).collect();
+                let dopplers: Vec<f64> = track.history.iter().map(|s| s.doppler as f64).collect();
+                let dts: Vec<f64> = timestamps.windows(2).map(|w| (w[1] - w[0]).max(1e-3)).collect();
+
+                let (smoothed_ranges, velocity) = smooth_series(&ranges, &dts, true, PROCESS_WEIGHT);
+                let (smoothed_dopplers, _) = smooth_series(&dopplers, &dts, false, PROCESS_WEIGHT);
+
+                let points = smoothed_ranges
+                    .into_iter()
+                    .zip(smoothed_dopplers)
+                    .map(|(range, doppler)| (range as f32, doppler as f32))
+                    .collect();
+
+                RenderedTrack {
+                    id: track.id,
+                    color: track.color,
+                    points,
+                    velocity: velocity as f32,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Normalized Euclidean distance between a predicted and candidate
+/// (range, doppler) pair, scaled so the gate constants read in their native
+/// units (meters, m/s) rather than as a unitless threshold.
+fn gated_distance(predicted: (f32, f32), candidate: (f32, f32)) -> f32 {
+    let d_range = (predicted.0 - candidate.0) / RANGE_GATE_M;
+    let d_doppler = (predicted.1 - candidate.1) / DOPPLER_GATE_MPS;
+    (d_range * d_range + d_doppler * d_doppler).sqrt()
+}
+
+/// Assigns each track id a visually distinct color via the golden-angle hue
+/// rotation, so ids never collide even as tracks spawn and die.
+fn palette_color(id: u64) -> (u8, u8, u8) {
+    let hue = (id as f32 * 137.508) % 360.0;
+    hsv_to_rgb(hue, 0.75, 0.95)
+}
+
+fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> (u8, u8, u8) {
+    let c = value * saturation;
+    let h_prime = hue / 60.0;
+    let x = c * (1.0 - (h_prime % 2.0 - 1.0).abs());
+    let (r1, g1, b1) = match h_prime as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = value - c;
+    (
+        ((r1 + m) * 255.0).round() as u8,
+        ((g1 + m) * 255.0).round() as u8,
+        ((b1 + m) * 255.0).round() as u8,
+    )
+}
+
+/// Fixed-lag least-squares smoother: minimizes the sum of squared
+/// measurement residuals `(z_k - x_k)` plus motion residuals
+/// `(x_{k+1} - x_k - v*dt_k)` over the window. Because the motion model is
+/// linear in the unknowns (`x_1..x_n` and, if `estimate_velocity`, a shared
+/// `v`), this is one normal-equation solve rather than an iterative filter.
+/// Falls back to the raw values if the system is singular (e.g. a
+/// single-sample track).
+fn smooth_series(
+    values: &[f64],
+    dts: &[f64],
+    estimate_velocity: bool,
+    process_weight: f64,
+) -> (Vec<f64>, f64) {
+    let n = values.len();
+    if n < 2 {
+        return (values.to_vec(), 0.0);
+    }
+
+    let velocity_index = n;
+    let unknowns = n + if estimate_velocity { 1 } else { 0 };
+    let mut normal = vec![vec![0.0f64; unknowns]; unknowns];
+    let mut rhs = vec![0.0f64; unknowns];
+
+    let mut add_residual = |coeffs: &[(usize, f64)], target: f64, weight: f64| {
+        for &(i, ci) in coeffs {
+            rhs[i] += weight * ci * target;
+            for &(j, cj) in coeffs {
+                normal[i][j] += weight * ci * cj;
+            }
+        }
+    };
+
+    for (k, &value) in values.iter().enumerate() {
+        add_residual(&[(k, 1.0)], value, 1.0);
+    }
+    for k in 0..n - 1 {
+        let dt = dts[k];
+        if estimate_velocity {
+            add_residual(&[(k + 1, 1.0), (k, -1.0), (velocity_index, -dt)], 0.0, process_weight);
+        } else {
+            add_residual(&[(k + 1, 1.0), (k, -1.0)], 0.0, process_weight);
+        }
+    }
+
+    match solve_linear_system(normal, rhs) {
+        Some(solution) => {
+            let smoothed = solution[0..n].to_vec();
+            let velocity = if estimate_velocity { solution[velocity_index] } else { 0.0 };
+            (smoothed, velocity)
+        }
+        None => (values.to_vec(), 0.0),
+    }
+}
+
+/// Solves the dense linear system `a·x = b` via Gaussian elimination with
+/// partial pivoting. Returns `None` if `a` is singular to working precision.
+fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let mut pivot_row = col;
+        let mut pivot_val = a[col][col].abs();
+        for row in (col + 1)..n {
+            if a[row][col].abs() > pivot_val {
+                pivot_val = a[row][col].abs();
+                pivot_row = row;
+            }
+        }
+        if pivot_val < 1e-9 {
+            return None;
+        }
+        a.swap(col, pivot_row);
+        b.swap(col, pivot_row);
+        for row in (col + 1)..n {
+            let factor = a[row][col] / a[col][col];
+            for k in col..n {
+                a[row][k] -= factor * a[col][k];
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let mut sum = b[row];
+        for k in (row + 1)..n {
+            sum -= a[row][k] * x[k];
+        }
+        x[row] = sum / a[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(range: f32, doppler: f32) -> DetectionRecord {
+        DetectionRecord::new(0.0, range, doppler, 15.0, 0.0, 0.0)
+    }
+
+    #[test]
+    fn solves_a_simple_linear_system() {
+        // x + y = 3, x - y = 1 -> x = 2, y = 1
+        let a = vec![vec![1.0, 1.0], vec![1.0, -1.0]];
+        let b = vec![3.0, 1.0];
+        let x = solve_linear_system(a, b).unwrap();
+        assert!((x[0] - 2.0).abs() < 1e-6);
+        assert!((x[1] - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn smooths_a_noisy_constant_velocity_series() {
+        // True motion: range 0,10,20,30 at doppler 10 m/s, dt=1s, with noise.
+        let values = vec![0.2, 9.8, 20.3, 29.7];
+        let dts = vec![1.0, 1.0, 1.0];
+        let (smoothed, velocity) = smooth_series(&values, &dts, true, 0.6);
+        assert_eq!(smoothed.len(), 4);
+        assert!((velocity - 10.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn a_detection_spawns_a_new_track() {
+        let mut tracker = Tracker::new();
+        tracker.update(&[record(1000.0, 10.0)], 0.0);
+        assert_eq!(tracker.rendered_tracks().len(), 1);
+    }
+
+    #[test]
+    fn a_close_followup_detection_continues_the_same_track() {
+        let mut tracker = Tracker::new();
+        tracker.update(&[record(1000.0, 10.0)], 0.0);
+        tracker.update(&[record(1010.0, 10.0)], 1.0);
+        let tracks = tracker.rendered_tracks();
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].points.len(), 2);
+    }
+
+    #[test]
+    fn a_far_detection_spawns_a_second_track_instead_of_associating() {
+        let mut tracker = Tracker::new();
+        tracker.update(&[record(1000.0, 10.0)], 0.0);
+        tracker.update(&[record(5000.0, -40.0)], 1.0);
+        assert_eq!(tracker.rendered_tracks().len(), 2);
+    }
+
+    #[test]
+    fn a_track_is_dropped_after_too_many_missed_frames() {
+        let mut tracker = Tracker::new();
+        tracker.update(&[record(1000.0, 10.0)], 0.0);
+        for tick in 1..=(MAX_MISSED_FRAMES as i64 + 1) {
+            tracker.update(&[], tick as f64);
+        }
+        assert!(tracker.rendered_tracks().is_empty());
+    }
+}